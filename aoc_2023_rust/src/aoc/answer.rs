@@ -0,0 +1,106 @@
+use std::fmt;
+
+// The answer to one part of a puzzle. Unsolved parts say so explicitly instead
+// of returning an empty answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    // The answer as it would be submitted, or None if the part isn't solved
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Answer::Unsolved => None,
+            answer => Some(answer.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => n.fmt(f),
+            Answer::BigInt(n) => n.fmt(f),
+            Answer::Text(s) => f.pad(s),
+            Answer::Unsolved => f.pad("(unsolved)"),
+        }
+    }
+}
+
+// Compare with the text of a solved answer, as in `assert_eq!(answer, "42")`
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.value().is_some_and(|value| value == *other)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::BigInt(n),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::from(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(142), Answer::Int(142));
+        assert_eq!(Answer::from(-3i64), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(1i128 << 70), Answer::BigInt(1 << 70));
+        assert_eq!(Answer::from(1i128), Answer::Int(1));
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+    }
+
+    #[test]
+    fn test_answer_value() {
+        assert_eq!(Answer::Int(142).value(), Some("142".to_string()));
+        assert_eq!(
+            Answer::BigInt(1 << 70).value(),
+            Some("1180591620717411303424".to_string())
+        );
+        assert_eq!(Answer::Text(String::new()).value(), Some(String::new()));
+        assert_eq!(Answer::Unsolved.value(), None);
+        assert_eq!(format!("{:>12}", Answer::Unsolved), "  (unsolved)");
+
+        assert_eq!(Answer::Int(142), "142");
+        assert_eq!(Answer::Text(String::new()), "");
+        assert_ne!(Answer::Unsolved, "");
+        assert_ne!(Answer::Unsolved, "(unsolved)");
+    }
+}
@@ -0,0 +1,162 @@
+use crate::aoc::Part;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io(path, err) => write!(f, "Couldn't access {}: {err}", path.display()),
+            AnswersError::Parse(path, err) => write!(f, "Invalid {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+// Accepted answers for the real puzzle inputs, stored as
+//
+//   [day1]
+//   part1 = "142"
+//   part2 = 281
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    // A missing file just means that no answers have been recorded yet
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|err| AnswersError::Parse(path.to_path_buf(), err))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text
+            .parse::<Table>()
+            .map_err(|err| err.message().to_string())?;
+        let mut answers = Self::default();
+        for (day_key, parts) in table.iter() {
+            let day = parse_day_key(day_key)?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("[{day_key}] should be a table"))?;
+            for (part_key, value) in parts.iter() {
+                let part = parse_part_key(part_key)?;
+                let answer = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "{day_key}.{part_key} should be a string or integer"
+                        ))
+                    }
+                };
+                answers.set(day, part, answer);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string()).map_err(|err| AnswersError::Io(path.to_path_buf(), err))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut current_day = None;
+        for ((day, part), answer) in self.answers.iter() {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day}]")?;
+                current_day = Some(day);
+            }
+            writeln!(f, "part{part} = {}", Value::String(answer.clone()))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn parse_day_key(key: &str) -> Result<u8, String> {
+    key.strip_prefix("day")
+        .and_then(|day| day.parse::<u8>().ok())
+        .filter(|day| (1..=25).contains(day))
+        .ok_or(format!("Unknown day: [{key}]"))
+}
+
+pub fn parse_part_key(key: &str) -> Result<Part, String> {
+    match key {
+        "part1" => Ok(Part::One),
+        "part2" => Ok(Part::Two),
+        _ => Err(format!("Unknown part: {key}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part1 = "142"
+part2 = 281
+
+[day13]
+part1 = "405"
+"#;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("142"));
+        assert_eq!(answers.get(1, Part::Two), Some("281"));
+        assert_eq!(answers.get(13, Part::One), Some("405"));
+        assert_eq!(answers.get(13, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_answers() {
+        assert!(Answers::parse("[day26]\npart1 = \"1\"").is_err());
+        assert!(Answers::parse("[day1]\npart3 = \"1\"").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("day1 = 3").is_err());
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let text = answers.to_string();
+        assert!(text.starts_with("[day1]\npart1 = \"142\"\npart2 = \"281\"\n\n[day13]"));
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+    }
+}
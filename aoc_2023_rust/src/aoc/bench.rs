@@ -0,0 +1,114 @@
+use crate::aoc::input::read_input_for_day;
+use crate::aoc::runner::select_solutions;
+use crate::aoc::solution::PartStatus;
+use crate::aoc::Part;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let output = f();
+    (output, start.elapsed())
+}
+
+// Time the parse step and each selected part of the given days `iterations`
+// times and print the min, median and max duration of each step.
+pub fn bench_days(days: &[u8], part: Option<Part>, iterations: usize) {
+    println!(
+        "{:>3}  {:<6} {:>5} {:>10} {:>10} {:>10}",
+        "Day", "Step", "Runs", "Min", "Median", "Max"
+    );
+    for solution in select_solutions(days) {
+        let day = solution.day();
+        let input = match read_input_for_day(day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{day:>3}  {err}");
+                continue;
+            }
+        };
+
+        let mut steps = vec![("parse", Vec::<Duration>::new())];
+        let (parsed, elapsed) = time(|| solution.parse(&input));
+        steps[0].1.push(elapsed);
+        for _ in 1..iterations {
+            steps[0].1.push(time(|| solution.parse(&input)).1);
+        }
+
+        for (name, current) in [("part1", Part::One), ("part2", Part::Two)] {
+            let skipped = matches!(solution.status(current), PartStatus::Skipped(_));
+            if current.is_selected_by(part) && !skipped {
+                let samples = (0..iterations)
+                    .map(|_| time(|| solution.solve(current, &parsed)).1)
+                    .collect::<Vec<Duration>>();
+                steps.push((name, samples));
+            }
+        }
+
+        for (name, samples) in steps {
+            if let Some(Summary { min, median, max }) = Summary::from_samples(&samples) {
+                println!(
+                    "{day:>3}  {name:<6} {:>5} {:>10} {:>10} {:>10}",
+                    samples.len(),
+                    format_duration(min),
+                    format_duration(median),
+                    format_duration(max)
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis);
+        let summary = Summary::from_samples(&samples).unwrap();
+        assert_eq!(summary.min, Duration::from_millis(1));
+        assert_eq!(summary.median, Duration::from_millis(3));
+        assert_eq!(summary.max, Duration::from_millis(5));
+        assert_eq!(Summary::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+}
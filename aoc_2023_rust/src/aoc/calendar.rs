@@ -0,0 +1,168 @@
+use crate::aoc::solution::{DynSolution, PartStatus};
+use crate::aoc::Part;
+
+pub const LAST_DAY: u8 = 25;
+// December 1st 2023 was a Friday, the fifth column of a week starting on Monday
+const FIRST_WEEKDAY: usize = 4;
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+pub const LEGEND: &str = "* solved  + ignored tests  ~ partial  - skipped  . unsolved";
+
+fn mark(status: PartStatus) -> char {
+    match status {
+        PartStatus::Solved => '*',
+        PartStatus::IgnoredTests => '+',
+        PartStatus::Partial => '~',
+        PartStatus::Skipped(_) => '-',
+        PartStatus::Unsolved => '.',
+    }
+}
+
+fn has_star(status: PartStatus) -> bool {
+    matches!(status, PartStatus::Solved | PartStatus::IgnoredTests)
+}
+
+// The status of both parts of a day, or None if the day has no module yet
+fn statuses_of(solutions: &[&dyn DynSolution], day: u8) -> Option<[PartStatus; 2]> {
+    let solution = solutions.iter().find(|solution| solution.day() == day)?;
+    Some([solution.status(Part::One), solution.status(Part::Two)])
+}
+
+// Days without a module, as ranges like "19-25"
+fn missing_days(solutions: &[&dyn DynSolution]) -> Vec<String> {
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    for day in (1..=LAST_DAY).filter(|&day| statuses_of(solutions, day).is_none()) {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == day => *last = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(first, last)| match first == last {
+            true => format!("{first}"),
+            false => format!("{first}-{last}"),
+        })
+        .collect()
+}
+
+// The December calendar with a mark for each part of each day, followed by
+// the number of stars and the parts that still need work.
+pub fn render(solutions: &[&dyn DynSolution]) -> String {
+    let mut text = String::from("        December 2023\n");
+    text += WEEKDAYS
+        .iter()
+        .map(|weekday| format!("{weekday:>3}   "))
+        .collect::<String>()
+        .trim_end();
+    text.push('\n');
+
+    let mut cells = vec!["      ".to_string(); FIRST_WEEKDAY];
+    let mut stars = 0;
+    let mut needs_work = Vec::new();
+    for day in 1..=LAST_DAY {
+        let marks = match statuses_of(solutions, day) {
+            Some(statuses) => {
+                for (part, status) in [Part::One, Part::Two].into_iter().zip(statuses) {
+                    if has_star(status) {
+                        stars += 1;
+                    }
+                    if status != PartStatus::Solved {
+                        needs_work.push(format!("  Day {day:>2} part {part}: {status}\n"));
+                    }
+                }
+                statuses.map(mark).iter().collect()
+            }
+            None => String::new(),
+        };
+        cells.push(format!("{day:>3} {marks:<2}"));
+    }
+    for week in cells.chunks(WEEKDAYS.len()) {
+        text += week.concat().trim_end();
+        text.push('\n');
+    }
+
+    text += &format!("\n{LEGEND}\nStars: {stars}/{}\n", LAST_DAY as usize * 2);
+    let missing = missing_days(solutions);
+    if !needs_work.is_empty() || !missing.is_empty() {
+        text += "\nNeeds work:\n";
+        text += &needs_work.concat();
+        let label = match missing.as_slice() {
+            [] => None,
+            [day] if !day.contains('-') => Some("Day"),
+            _ => Some("Days"),
+        };
+        if let Some(label) = label {
+            text += &format!("  {label} {}: not started\n", missing.join(", "));
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::answer::Answer;
+    use crate::aoc::solution::Solution;
+
+    struct First;
+
+    impl Solution for First {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "First";
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Self::Input {}
+
+        fn part1(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    struct Fourth;
+
+    impl Solution for Fourth {
+        const DAY: u8 = 4;
+        const TITLE: &'static str = "Fourth";
+        const STATUS: [PartStatus; 2] = [PartStatus::Partial, PartStatus::Skipped("too slow")];
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Self::Input {}
+
+        fn part1(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_missing_days() {
+        assert_eq!(missing_days(&[&First, &Fourth]), vec!["2-3", "5-25"]);
+        assert_eq!(missing_days(&[&Fourth]), vec!["1-3", "5-25"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let calendar = render(&[&First, &Fourth]);
+        let lines = calendar.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[1], "Mon   Tue   Wed   Thu   Fri   Sat   Sun");
+        assert_eq!(lines[2], "                          1 **  2     3");
+        assert_eq!(lines[3], "  4 ~-  5     6     7     8     9    10");
+        assert_eq!(lines[6], " 25");
+        assert!(calendar.contains("\nStars: 2/50\n"));
+        assert!(calendar.ends_with(
+            "Needs work:\n  Day  4 part 1: partially solved\n  Day  4 part 2: skipped, too slow\n  Days 2-3, 5-25: not started\n"
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::aoc::answers::{Answers, ANSWERS_FILE};
+use crate::aoc::input::{base_input_dir, input_dir_for, profiles_in, InputSource};
+use crate::aoc::rejected::Rejected;
+use crate::aoc::report::{Record, Status};
+use crate::aoc::runner::{run, select_solutions, RunOptions};
+use crate::aoc::Part;
+use std::time::Duration;
+
+// The answers of every profile for one part of a day
+#[derive(Debug, PartialEq, Eq)]
+struct Row {
+    day: u8,
+    part: Part,
+    cells: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Tally {
+    mismatches: usize,
+    failed: usize,
+    missing: usize,
+}
+
+// What the table says about a part for one profile, counting anything that
+// needs a closer look
+fn cell_for(record: &Record, expected: Option<&str>, tally: &mut Tally) -> String {
+    match (record.status, record.answer.as_deref()) {
+        (Status::Ok | Status::Warning, Some(answer)) => match expected {
+            Some(expected) if expected != answer => {
+                tally.mismatches += 1;
+                format!("{answer} (expected {expected})")
+            }
+            _ => answer.to_string(),
+        },
+        (Status::Failed, _) => {
+            tally.failed += 1;
+            "FAILED".to_string()
+        }
+        (Status::Timeout, _) => {
+            tally.failed += 1;
+            "TIMEOUT".to_string()
+        }
+        (Status::Error, _) => {
+            tally.missing += 1;
+            "-".to_string()
+        }
+        (Status::Skipped, _) => "skipped".to_string(),
+        _ => "(unsolved)".to_string(),
+    }
+}
+
+fn render_table(profiles: &[String], rows: &[Row]) -> String {
+    let widths = profiles
+        .iter()
+        .enumerate()
+        .map(|(ix, profile)| {
+            rows.iter()
+                .map(|row| row.cells[ix].len())
+                .chain([profile.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<usize>>();
+    let line = |day: &str, part: &str, cells: &[String]| {
+        let cells = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<String>>()
+            .join("  ");
+        format!("{day:>3}  {part:>4}  {cells}")
+            .trim_end()
+            .to_string()
+            + "\n"
+    };
+
+    let mut table = line("Day", "Part", profiles);
+    for row in rows {
+        table += &line(&row.day.to_string(), &row.part.to_string(), &row.cells);
+    }
+    table
+}
+
+// Solve the selected days for the inputs of every profile in $AOC_INPUT_DIR
+// and print the answers side by side. Each answer is checked against the
+// `answers.toml` of its profile. Returns false if any answer differs from
+// it or any solver panicked or timed out.
+pub fn compare_profiles(days: &[u8], part: Option<Part>, timeout: Option<Duration>) -> bool {
+    let profiles = match base_input_dir().and_then(|dir| profiles_in(&dir)) {
+        Ok(profiles) if !profiles.is_empty() => profiles,
+        Ok(_) => {
+            println!("No profiles found, expected inputs in $AOC_INPUT_DIR/<profile>/dayN.txt");
+            return false;
+        }
+        Err(err) => {
+            println!("{err}");
+            return false;
+        }
+    };
+    let mut answers = Vec::new();
+    for profile in profiles.iter() {
+        let loaded = input_dir_for(Some(profile))
+            .map_err(|err| err.to_string())
+            .and_then(|dir| Answers::load(&dir.join(ANSWERS_FILE)).map_err(|err| err.to_string()));
+        match loaded {
+            Ok(loaded) => answers.push(loaded),
+            Err(err) => {
+                println!("{err}");
+                return false;
+            }
+        }
+    }
+
+    let mut tally = Tally::default();
+    let mut rows = Vec::new();
+    for solution in select_solutions(days) {
+        let day = solution.day();
+        let mut day_rows = [Part::One, Part::Two]
+            .into_iter()
+            .filter(|current| current.is_selected_by(part))
+            .map(|part| Row {
+                day,
+                part,
+                cells: Vec::new(),
+            })
+            .collect::<Vec<Row>>();
+        for (profile, answers) in profiles.iter().zip(&answers) {
+            let options = RunOptions {
+                part,
+                timeout,
+                input: InputSource::Profile(profile.clone()),
+                ..Default::default()
+            };
+            let report = run(solution, &options, &Rejected::default());
+            for (row, record) in day_rows.iter_mut().zip(&report.records) {
+                let expected = answers.get(day, record.part);
+                row.cells.push(cell_for(record, expected, &mut tally));
+            }
+        }
+        rows.extend(day_rows);
+    }
+    print!("{}", render_table(&profiles, &rows));
+    println!();
+    println!(
+        "{} answer(s) differ from answers.toml, {} failed, {} missing input(s)",
+        tally.mismatches, tally.failed, tally.missing
+    );
+    tally.mismatches == 0 && tally.failed == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(status: Status, answer: Option<&str>) -> Record {
+        Record {
+            day: 8,
+            part: Part::Two,
+            answer: answer.map(String::from),
+            duration: None,
+            status,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_cell_for() {
+        let mut tally = Tally::default();
+        let ok = record(Status::Ok, Some("6"));
+        assert_eq!(cell_for(&ok, None, &mut tally), "6");
+        assert_eq!(cell_for(&ok, Some("6"), &mut tally), "6");
+        assert_eq!(cell_for(&ok, Some("7"), &mut tally), "6 (expected 7)");
+        assert_eq!(
+            cell_for(&record(Status::Failed, None), Some("7"), &mut tally),
+            "FAILED"
+        );
+        assert_eq!(
+            cell_for(&record(Status::Error, None), None, &mut tally),
+            "-"
+        );
+        assert_eq!(
+            cell_for(&record(Status::Unsolved, None), None, &mut tally),
+            "(unsolved)"
+        );
+        assert_eq!(
+            tally,
+            Tally {
+                mismatches: 1,
+                failed: 1,
+                missing: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let profiles = vec!["alice".to_string(), "bob".to_string()];
+        let rows = vec![
+            Row {
+                day: 8,
+                part: Part::One,
+                cells: vec!["2".to_string(), "19951".to_string()],
+            },
+            Row {
+                day: 8,
+                part: Part::Two,
+                cells: vec!["6 (expected 7)".to_string(), "-".to_string()],
+            },
+        ];
+        assert_eq!(
+            render_table(&profiles, &rows),
+            "Day  Part  alice           bob\n  \
+               8     1  2               19951\n  \
+               8     2  6 (expected 7)  -\n"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use crate::aoc::parse_days_from;
+use crate::aoc::report::Format;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use toml::{Table, Value};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+// Where the inputs are when the config doesn't say, relative to its directory
+pub const DEFAULT_INPUT_DIR: &str = "../inputs";
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "Couldn't access {}: {err}", path.display()),
+            ConfigError::Parse(path, err) => write!(f, "Invalid {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// Project settings, stored as
+//
+//   input_dir = "../inputs"
+//   profile = "alice"
+//   days = "1-18"
+//   timeout = 30
+//   format = "text"
+//   jobs = 4
+//
+//   [fetch]
+//   base_url = "https://adventofcode.com"
+//   delay = 5
+//   session_token = "53616c74..."
+//
+// Every setting is optional. Command line flags override them, and so do
+// environment variables that are already set.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub input_dir: Option<PathBuf>,
+    pub profile: Option<String>,
+    pub days: Vec<u8>,
+    pub timeout: Option<Duration>,
+    pub format: Option<Format>,
+    pub jobs: Option<usize>,
+    pub base_url: Option<String>,
+    pub delay: Option<Duration>,
+    pub session_token: Option<String>,
+}
+
+// $AOC_CONFIG when it's set. Otherwise the nearest aoc.toml in the current
+// directory or above it, and failing that the one next to this crate's
+// Cargo.toml, which is found when running from the root of the repository.
+pub fn config_path() -> PathBuf {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return PathBuf::from(path);
+    }
+    env::current_dir()
+        .ok()
+        .and_then(|dir| find_config_from(&dir))
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE))
+}
+
+// The aoc.toml in `dir` or the closest of its parents
+pub fn find_config_from(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+fn string_in(table: &Table, key: &str) -> Result<Option<String>, String> {
+    match table.get(key) {
+        None => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s.clone())),
+        Some(_) => Err(format!("{key} should be a string")),
+    }
+}
+
+fn seconds_in(table: &Table, key: &str, allow_zero: bool) -> Result<Option<Duration>, String> {
+    let seconds = match table.get(key) {
+        None => return Ok(None),
+        Some(Value::Integer(n)) => *n as f64,
+        Some(Value::Float(x)) => *x,
+        Some(_) => return Err(format!("{key} should be a number of seconds")),
+    };
+    match seconds {
+        s if s > 0.0 || (allow_zero && s == 0.0) => Ok(Some(Duration::from_secs_f64(s))),
+        _ => Err(format!("{key} should be more than 0 seconds")),
+    }
+}
+
+fn check_keys(table: &Table, known: &[&str], section: &str) -> Result<(), String> {
+    match table.keys().find(|key| !known.contains(&key.as_str())) {
+        Some(key) => Err(format!("unknown setting {section}{key}")),
+        None => Ok(()),
+    }
+}
+
+impl Config {
+    // A missing file just means that everything is left at its default.
+    // A relative input_dir is relative to the directory of the file, and
+    // without one the inputs are in DEFAULT_INPUT_DIR from there.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|err| ConfigError::Parse(path.to_path_buf(), err))?
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(ConfigError::Io(path.to_path_buf(), err)),
+        };
+        let input_dir = config
+            .input_dir
+            .take()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+        config.input_dir = Some(match path.parent() {
+            Some(parent) => parent.join(input_dir),
+            None => input_dir,
+        });
+
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text
+            .parse::<Table>()
+            .map_err(|err| err.message().to_string())?;
+        check_keys(
+            &table,
+            &[
+                "input_dir",
+                "profile",
+                "days",
+                "timeout",
+                "format",
+                "jobs",
+                "fetch",
+            ],
+            "",
+        )?;
+
+        let mut days = match table.get("days") {
+            None => Vec::new(),
+            Some(Value::String(spec)) => parse_days_from(spec)?,
+            Some(Value::Array(days)) => {
+                let mut parsed = Vec::new();
+                for day in days {
+                    let spec = match day {
+                        Value::Integer(n) => n.to_string(),
+                        Value::String(s) => s.clone(),
+                        _ => return Err("days should be day numbers or ranges".to_string()),
+                    };
+                    parsed.extend(parse_days_from(&spec)?);
+                }
+                parsed
+            }
+            Some(_) => return Err("days should be a string like \"1-18\" or a list".to_string()),
+        };
+        days.sort_unstable();
+        days.dedup();
+        let jobs = match table.get("jobs") {
+            None => None,
+            Some(Value::Integer(n)) if *n > 0 => Some(*n as usize),
+            Some(_) => return Err("jobs should be a number above 0".to_string()),
+        };
+        let format = string_in(&table, "format")?
+            .map(|format| format.parse::<Format>())
+            .transpose()?;
+
+        let fetch = match table.get("fetch") {
+            None => Table::new(),
+            Some(Value::Table(fetch)) => fetch.clone(),
+            Some(_) => return Err("[fetch] should be a table".to_string()),
+        };
+        check_keys(&fetch, &["base_url", "delay", "session_token"], "fetch.")?;
+
+        Ok(Self {
+            input_dir: string_in(&table, "input_dir")?.map(PathBuf::from),
+            profile: string_in(&table, "profile")?,
+            days,
+            timeout: seconds_in(&table, "timeout", false)?,
+            format,
+            jobs,
+            base_url: string_in(&fetch, "base_url")?,
+            delay: seconds_in(&fetch, "delay", true)?,
+            session_token: string_in(&fetch, "session_token")?,
+        })
+    }
+
+    // Make the settings that are read from the environment available there,
+    // without replacing variables that are already set
+    pub fn apply_to_env(&self) {
+        let settings = [
+            (
+                "AOC_INPUT_DIR",
+                self.input_dir.as_ref().map(|dir| dir.display().to_string()),
+            ),
+            ("AOC_PROFILE", self.profile.clone()),
+            ("AOC_BASE_URL", self.base_url.clone()),
+            ("AOC_SESSION_TOKEN", self.session_token.clone()),
+        ];
+        for (name, value) in settings {
+            if let (None, Some(value)) = (env::var_os(name), value) {
+                env::set_var(name, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            r#"
+input_dir = "../inputs"
+profile = "alice"
+days = "1-3"
+timeout = 2.5
+format = "csv"
+jobs = 4
+
+[fetch]
+delay = 0
+session_token = "abc"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                input_dir: Some(PathBuf::from("../inputs")),
+                profile: Some("alice".to_string()),
+                days: vec![1, 2, 3],
+                timeout: Some(Duration::from_millis(2500)),
+                format: Some(Format::Csv),
+                jobs: Some(4),
+                base_url: None,
+                delay: Some(Duration::ZERO),
+                session_token: Some("abc".to_string()),
+            }
+        );
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(
+            Config::parse("days = [6, 1, \"5-6\"]").unwrap().days,
+            vec![1, 5, 6]
+        );
+
+        assert!(Config::parse("timeout = 0").is_err());
+        assert!(Config::parse("jobs = 0").is_err());
+        assert!(Config::parse("format = \"xml\"").is_err());
+        assert!(Config::parse("days = \"26\"").is_err());
+        assert!(Config::parse("input = \"../inputs\"").is_err());
+        assert!(Config::parse("[fetch]\ndelai = 1").is_err());
+        assert!(Config::parse("fetch = 1").is_err());
+    }
+
+    #[test]
+    fn test_load_config() {
+        let dir = env::temp_dir().join(format!("aoc_2023_config_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(CONFIG_FILE);
+
+        let defaults = Config {
+            input_dir: Some(dir.join(DEFAULT_INPUT_DIR)),
+            ..Default::default()
+        };
+        assert_eq!(Config::load(&path).unwrap(), defaults);
+        fs::write(&path, "days = \"3\"\n").unwrap();
+        assert_eq!(
+            Config::load(&path).unwrap().input_dir,
+            Some(dir.join(DEFAULT_INPUT_DIR))
+        );
+        fs::write(&path, "input_dir = \"inputs\"\njobs = 2\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.input_dir, Some(dir.join("inputs")));
+        assert_eq!(config.jobs, Some(2));
+        fs::write(&path, "jobs = \"many\"\n").unwrap();
+        assert!(matches!(Config::load(&path), Err(ConfigError::Parse(_, _))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_find_config() {
+        let dir = env::temp_dir().join(format!("aoc_2023_find_config_{}", std::process::id()));
+        let nested = dir.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.join(CONFIG_FILE), "").unwrap();
+        assert_eq!(find_config_from(&nested), Some(dir.join(CONFIG_FILE)));
+        fs::write(nested.join(CONFIG_FILE), "").unwrap();
+        assert_eq!(find_config_from(&nested), Some(nested.join(CONFIG_FILE)));
+        fs::remove_dir_all(dir).unwrap();
+
+        let crate_config = Path::new(env!("CARGO_MANIFEST_DIR")).join(CONFIG_FILE);
+        assert_eq!(
+            find_config_from(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src")),
+            Some(crate_config)
+        );
+    }
+}
@@ -1,15 +1,27 @@
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::solution::Solution;
 use lazy_static::lazy_static;
 use regex::Regex;
 
-pub fn run() {
-    println!("Day 1 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(1);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
 fn clean_input(input: &str) -> String {
@@ -1,119 +1,92 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::point::{Direction, Point};
+use crate::aoc::search;
+use crate::aoc::solution::{PartStatus, Solution};
 
 use std::collections::HashSet;
 
-pub fn run() {
-    println!("Day 10 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(10);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Unsolved];
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
-type Coordinate = (usize, usize);
+type Coordinate = Point;
 type Coordinates = HashSet<Coordinate>;
 
-fn find_starting_point(input: &String) -> Coordinate {
-    let nrows = input.len();
-    let ncols = input.split('\n').nth(0).unwrap().len();
-    for (r, line) in input.split('\n').enumerate() {
-        for (c, ch) in line.chars().enumerate() {
-            if ch == 'S' {
-                return (r, c);
-            }
-        }
-    }
+fn find_starting_point(input: &Grid<char>) -> Coordinate {
+    input
+        .find(|&ch| ch == 'S')
+        .map(Point::from)
+        .expect("No starting point found!")
+}
 
-    panic!("No starting point found!");
+fn get_pipe_at(coor: &Coordinate, input: &Grid<char>) -> char {
+    input.get_point(*coor).copied().unwrap_or(' ')
 }
 
-fn get_pipe_at(coor: &Coordinate, input: &String) -> char {
-    let (r, c) = coor;
-    if let Some(line) = input.split('\n').nth(*r) {
-        if let Some(ch) = line.chars().nth(*c) {
-            return ch;
-        }
+// The directions to the two ends of a pipe
+fn pipe_ends(pipe: char) -> Vec<Direction> {
+    match pipe {
+        '|' => vec![Direction::Up, Direction::Down],
+        '-' => vec![Direction::Left, Direction::Right],
+        'L' => vec![Direction::Right, Direction::Up],
+        'J' => vec![Direction::Left, Direction::Up],
+        '7' => vec![Direction::Left, Direction::Down],
+        'F' => vec![Direction::Right, Direction::Down],
+        _ => vec![],
     }
+}
 
-    ' '
+// Whether one of the ends of the pipe at `from` is `to`
+fn leads_to(from: &Coordinate, to: &Coordinate, input: &Grid<char>) -> bool {
+    pipe_ends(get_pipe_at(from, input))
+        .into_iter()
+        .map(|dir| from.step(dir))
+        .any(|end| end == *to)
 }
 
-fn can_connect(a: &Coordinate, b: &Coordinate, input: &String) -> bool {
+fn can_connect(a: &Coordinate, b: &Coordinate, input: &Grid<char>) -> bool {
     if a == b { return false; }
     let mut connections_found = Vec::<bool>::new();
     for (coor_a, coor_b) in vec![(a, b), (b, a)] {
-        let (ra, ca) = coor_a;
-        let connections = match get_pipe_at(coor_a, input) {
-            '|' => vec![
-                (ra.checked_sub(1).unwrap_or(*ra), *ca),
-                (ra.checked_add(1).unwrap_or(*ra), *ca),
-            ],
-            '-' => vec![
-                (*ra, ca.checked_sub(1).unwrap_or(*ca)),
-                (*ra, ca.checked_add(1).unwrap_or(*ca)),
-            ],
-            'L' => vec![
-                (*ra, ca.checked_add(1).unwrap_or(*ca)),
-                (ra.checked_sub(1).unwrap_or(*ra), *ca),
-            ],
-            'J' => vec![
-                (*ra, ca.checked_sub(1).unwrap_or(*ca)),
-                (ra.checked_sub(1).unwrap_or(*ra), *ca),
-            ],
-            '7' => vec![
-                (*ra, ca.checked_sub(1).unwrap_or(*ca)),
-                (ra.checked_add(1).unwrap_or(*ra), *ca),
-            ],
-            'F' => vec![
-                (*ra, ca.checked_add(1).unwrap_or(*ca)),
-                (ra.checked_add(1).unwrap_or(*ra), *ca),
-            ],
-            'S' => return get_pipe_at(coor_b, input) != '.',
-            _ => vec![],
-        };
-
-        for connection in connections {
-            if *coor_b == connection {
-                connections_found.push(true);
-            }
+        // S could be any pipe, so it connects to the pipes that lead back to it
+        if get_pipe_at(coor_a, input) == 'S' {
+            return leads_to(coor_b, coor_a, input);
+        }
+
+        if leads_to(coor_a, coor_b, input) {
+            connections_found.push(true);
         }
     }
     connections_found.len() > 1
 }
 
-fn find_next_connection_of(
-    coor: &Coordinate,
-    prev: &Coordinate,
-    input: &String,
-) -> Option<Coordinate> {
-    // TODO: Check if this function works as expected
-    let connections = find_connections_to(coor, input);
-    let next_connections = connections
-        .iter()
-        .filter(|conn| *conn != prev)
-        .map(|conn| *conn)
-        .collect::<Coordinates>();
-    //println!("Next connections: {next_connections:?}");
-
-    next_connections.into_iter().next()
-}
-
-fn find_connections_to(coor: &Coordinate, input: &String) -> Coordinates {
+fn find_connections_to(coor: &Coordinate, input: &Grid<char>) -> Coordinates {
     let mut connections = Coordinates::new();
 
-    let (r, c) = coor;
-    let dirs = vec![(-1, 0), (0, 1), (1, 0), (0, -1)];
-    for (rx, cx) in dirs {
-        let connection = (
-            r.checked_add_signed(rx).unwrap_or(*r),
-            c.checked_add_signed(cx).unwrap_or(*c),
-        );
-        if can_connect(coor, &connection, input) {
+    for connection in coor.neighbors4() {
+        if input.contains_point(connection) && can_connect(coor, &connection, input) {
             connections.insert(connection);
         }
     }
@@ -121,48 +94,25 @@ fn find_connections_to(coor: &Coordinate, input: &String) -> Coordinates {
     connections
 }
 
-fn solve_part1(input: &String) -> String {
+// The farthest point of the loop is the most steps away from the start
+fn solve_part1(input: &Grid<char>) -> String {
     let start = find_starting_point(input);
-    let start_connections = find_connections_to(&start, input);
-    let mut steps = 0;
-    //println!("=================");
-    //println!("{start:?} {}", get_pipe_at(&start, input));
-    //println!("{start_connections:?}");
-    //println!("-----------------");
-    for conn in start_connections {
-        let mut step = 4;
-        let mut next = conn;
-        let mut prev = start.clone();
-        //println!(".................");
-
-        while let Some(next_next) = find_next_connection_of(&next, &prev, input) {
-            //println!("{next:?} {}", get_pipe_at(&next, input));
-            prev = next;
-            next = next_next;
-            step += 1;
-            if next == start {
-                //println!("START FOUND!");
-                break;
-            }
-        }
-        steps = std::cmp::max(steps, step);
-    }
-    if steps % 2 == 0 {
-        (steps / 2).to_string()
-    } else {
-        ((steps / 2) + 1).to_string()
-    }
+    search::bfs_distances([start], |coor| find_connections_to(coor, input))
+        .into_values()
+        .max()
+        .unwrap_or(0)
+        .to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    String::new()
+fn solve_part2(input: &Grid<char>) -> Answer {
+    Answer::Unsolved
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const INPUT: [&str; 3] = [
+    const INPUT: [&str; 4] = [
         "
 .....
 .S-7.
@@ -192,21 +142,31 @@ LJ...
 SJLL7
 |F--J
 LJ.LJ",
+        // The pipes left of S don't lead to it, so they aren't part of the loop
+        "
+|....
+|S-7.
+||.|.
+|L-J.
+|....
+|....
+|....
+",
     ];
 
-    fn get_input(ix: usize) -> String {
-        String::from(INPUT[ix].trim())
+    fn get_input(ix: usize) -> Grid<char> {
+        Day10::parse(INPUT[ix])
     }
 
     #[test]
     fn test_find_starting_point() {
         let input = get_input(0);
         let start_coor = find_starting_point(&input);
-        assert_eq!(start_coor, (1, 1));
+        assert_eq!(start_coor, Point::new(1, 1));
 
         let input = get_input(1);
         let start_coor = find_starting_point(&input);
-        assert_eq!(start_coor, (2, 0));
+        assert_eq!(start_coor, Point::new(2, 0));
     }
 
     #[test]
@@ -214,7 +174,7 @@ LJ.LJ",
         let input = get_input(0);
         let start = find_starting_point(&input);
         let coors = find_connections_to(&start, &input);
-        let expected_coors = Coordinates::from_iter([(1, 2), (2, 1)].into_iter());
+        let expected_coors = Coordinates::from_iter([(1, 2), (2, 1)].map(Point::from));
 
         assert_eq!(coors, expected_coors);
     }
@@ -229,7 +189,8 @@ LJ.LJ",
             ((3, 3), (3, 2)),
         ];
 
-        for (a, b) in connections.iter() {
+        for &(a, b) in connections.iter() {
+            let (a, b) = (&Point::from(a), &Point::from(b));
             assert!(
                 can_connect(a, b, &input),
                 "{a:?} doesn't connect to {b:?} but it should!"
@@ -245,7 +206,8 @@ LJ.LJ",
             ((0, 0), (0, 0)),
         ];
 
-        for (a, b) in not_connections.iter() {
+        for &(a, b) in not_connections.iter() {
+            let (a, b) = (&Point::from(a), &Point::from(b));
             assert!(
                 !can_connect(a, b, &input),
                 "{a:?} connects to {b:?}, but it shouldn't!"
@@ -253,12 +215,24 @@ LJ.LJ",
         }
     }
 
+    #[test]
+    fn test_can_connect_to_start() {
+        let input = get_input(3);
+        let start = Point::new(1, 1);
+        assert!(can_connect(&start, &Point::new(1, 2), &input));
+        assert!(can_connect(&Point::new(2, 1), &start, &input));
+        assert!(!can_connect(&start, &Point::new(1, 0), &input));
+        assert!(!can_connect(&Point::new(1, 0), &start, &input));
+        assert!(can_connect(&Point::new(1, 0), &Point::new(2, 0), &input));
+    }
+
     #[test]
     fn test_can_connect_1() {
         let input = get_input(1);
         let connections = vec![((0, 2), (0, 3)), ((0, 3), (1, 3))];
 
-        for (a, b) in connections.iter() {
+        for &(a, b) in connections.iter() {
+            let (a, b) = (&Point::from(a), &Point::from(b));
             assert!(
                 can_connect(a, b, &input),
                 "{a:?} doesn't connect to {b:?} but it should!"
@@ -272,7 +246,8 @@ LJ.LJ",
             ((1, 3), (1, 4)),
         ];
 
-        for (a, b) in not_connections.iter() {
+        for &(a, b) in not_connections.iter() {
+            let (a, b) = (&Point::from(a), &Point::from(b));
             assert!(
                 !can_connect(a, b, &input),
                 "{a:?} connects to {b:?}, but it shouldn't!"
@@ -290,8 +265,18 @@ LJ.LJ",
         assert_eq!(solve_part1(&get_input(1)), "8");
     }
 
+    #[test]
+    fn test_full_part1_2() {
+        assert_eq!(solve_part1(&get_input(2)), "8");
+    }
+
+    #[test]
+    fn test_full_part1_3() {
+        assert_eq!(solve_part1(&get_input(3)), "4");
+    }
+
     #[test]
     fn test_full_part2() {
-        assert_eq!(solve_part2(&get_input(0)), "");
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
     }
 }
@@ -1,58 +1,67 @@
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::solution::{PartStatus, Solution};
 use std::collections::HashSet;
 
-pub fn run() {
-    println!("Day 11 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(11);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::IgnoredTests];
+
+    type Input = Universe;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
 
-type Universe = String;
-fn parse_universe_from(input: &String) -> Universe {
-    input.clone()
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
+type Universe = Grid<char>;
+
 fn find_empty_rows(universe: &Universe) -> HashSet<usize> {
     let mut empty_rows = HashSet::<usize>::new();
-    for (r, row) in universe.split('\n').enumerate() {
-        if HashSet::<char>::from_iter(row.chars()).len() == 1 {
+    for (r, row) in universe.iter_rows().enumerate() {
+        if row.iter().all(|&ch| ch == '.') {
             empty_rows.insert(r);
         }
     }
     empty_rows
 }
 
+fn find_empty_cols(universe: &Universe) -> HashSet<usize> {
+    let mut empty_cols = HashSet::<usize>::new();
+    for (c, mut col) in universe.iter_columns().enumerate() {
+        if col.all(|&ch| ch == '.') {
+            empty_cols.insert(c);
+        }
+    }
+    empty_cols
+}
+
 type Galaxy = (usize, usize);
 type Galaxies = Vec<Galaxy>;
 fn find_galaxies_in_expanded(universe: &Universe, expansion: usize) -> Vec<Galaxy> {
     let empty_rows = find_empty_rows(universe);
-
-    let ncols = universe.split('\n').nth(0).unwrap().len();
-    let rotated_universe = (0..ncols)
-        .map(|c| {
-            universe
-                .split('\n')
-                .map(|r| r.chars().nth(c).unwrap())
-                .collect::<String>()
-        })
-        .map(|col| col + "\n")
-        .collect::<String>();
-
-    let empty_cols = find_empty_rows(&rotated_universe);
+    let empty_cols = find_empty_cols(universe);
 
     let mut galaxies = Vec::<(usize, usize)>::new();
     let mut row_mod = 0;
-    for (r, row) in universe.split('\n').enumerate() {
+    for (r, row) in universe.iter_rows().enumerate() {
         let mut col_mod = 0;
         if empty_rows.contains(&r) {
             row_mod += expansion - 1;
             continue;
         }
-        for (c, col) in row.chars().enumerate() {
+        for (c, &col) in row.iter().enumerate() {
             if empty_cols.contains(&c) {
                 col_mod += expansion - 1;
                 continue;
@@ -93,9 +102,8 @@ fn calc_distance_between(a: &Galaxy, b: &Galaxy) -> usize {
     r + c
 }
 
-fn solve_part1(input: &String) -> String {
-    let universe = parse_universe_from(input);
-    let galaxies = find_galaxies_in_expanded(&universe, 2);
+fn solve_part1(universe: &Universe) -> String {
+    let galaxies = find_galaxies_in_expanded(universe, 2);
     let pairs = calculate_pairs_of(&galaxies);
     pairs
         .iter()
@@ -104,9 +112,8 @@ fn solve_part1(input: &String) -> String {
         .to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    let universe = parse_universe_from(input);
-    let galaxies = find_galaxies_in_expanded(&universe, 1_000_000);
+fn solve_part2(universe: &Universe) -> String {
+    let galaxies = find_galaxies_in_expanded(universe, 1_000_000);
     let pairs = calculate_pairs_of(&galaxies);
     pairs
         .iter()
@@ -132,8 +139,8 @@ mod test {
 #...#.....
 "];
 
-    fn get_input(ix: usize) -> String {
-        String::from(INPUT[ix].trim())
+    fn get_input(ix: usize) -> Universe {
+        Day11::parse(INPUT[ix])
     }
 
     #[test]
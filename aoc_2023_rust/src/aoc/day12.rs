@@ -1,16 +1,29 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
-
-pub fn run() {
-    println!("Day 12 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(12);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+use crate::aoc::answer::Answer;
+use crate::aoc::solution::{PartStatus, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hot Springs";
+    const STATUS: [PartStatus; 2] = [PartStatus::Partial, PartStatus::Unsolved];
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
 type Record = (String, Vec<usize>);
@@ -50,8 +63,8 @@ fn solve_part1(input: &String) -> String {
         .to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    String::new()
+fn solve_part2(input: &String) -> Answer {
+    Answer::Unsolved
 }
 
 #[cfg(test)]
@@ -99,6 +112,6 @@ mod test {
     #[test]
     #[ignore]
     fn test_full_part2() {
-        assert_eq!(solve_part2(&get_input(0)), "");
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
     }
 }
@@ -1,43 +1,41 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
-
-pub fn run() {
-    println!("Day 13 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(13);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::solution::Solution;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
+    type Input = Patterns;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_patterns_from(input.trim())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
-type Pattern = Vec<String>;
+type Pattern = Grid<char>;
 type Patterns = Vec<Pattern>;
 
-fn parse_patterns_from(input: &String) -> Patterns {
+fn parse_patterns_from(input: &str) -> Patterns {
     input
         .split("\n\n")
-        .map(|p| p.split('\n').map(|s| s.to_string()).collect::<Pattern>())
+        .map(|p| p.parse::<Pattern>().unwrap_or_else(|err| panic!("{err}")))
         .collect::<Patterns>()
 }
 
-fn rotate(pattern: &Pattern) -> Pattern {
-    let n_cols = pattern[0].len();
-    let mut rotated_pattern = Pattern::new();
-    for c in 0..n_cols {
-        let mut col = Vec::<char>::new();
-        for row in pattern.iter().rev() {
-            col.push(row.chars().nth(c).unwrap());
-        }
-        let rotated = col.iter().collect::<String>();
-        rotated_pattern.push(rotated);
-    }
-
-    rotated_pattern
-}
-
 fn find_reflection_in_rows_of(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
     // loop through all row indexes.
     //  - check two adjacent rows at each index
@@ -46,14 +44,14 @@ fn find_reflection_in_rows_of(pattern: &Pattern) -> (Option<usize>, Option<usize
     //    - If a mismatch is detected, continue trying the next row of the pattern
     //    - If the end of the pattern is reached, then the reflection index has been found
     let mut i = 0;
-    while i < pattern.len() - 1 {
-        if pattern[i] == pattern[i + 1] {
+    while i < pattern.rows() - 1 {
+        if pattern.row(i) == pattern.row(i + 1) {
             let mut ix = 1;
             loop {
-                if i.checked_sub(ix).is_none() || i + ix + 1 >= pattern.len() {
+                if i.checked_sub(ix).is_none() || i + ix + 1 >= pattern.rows() {
                     return (Some(i + 1), None);
                 }
-                if pattern[i - ix] != pattern[i + ix + 1] {
+                if pattern.row(i - ix) != pattern.row(i + ix + 1) {
                     break;
                 }
                 ix += 1
@@ -71,7 +69,7 @@ fn find_reflection_in(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
         return (r, c);
     }
     // Find first reflected columns if reflected rows weren't found.
-    let rotated = rotate(pattern);
+    let rotated = pattern.rotate_clockwise();
     let (r, c) = find_reflection_in_rows_of(&rotated);
     if r.is_some() || c.is_some() {
         return (c, r);
@@ -81,17 +79,13 @@ fn find_reflection_in(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
 }
 
 fn find_reflection_in_modified(pattern: &Pattern) -> (Option<usize>, Option<usize>) {
-    let n_rows = pattern[0].len();
     let (mut r, mut c): (Option<usize>, Option<usize>) = (None, None);
     let mut row_reflection_found = false;
-    for i in 0..pattern.len() {
-        for j in 0..n_rows {
+    for i in 0..pattern.rows() {
+        for j in 0..pattern.cols() {
             let mut temp_pattern = pattern.clone();
-            let mut row = temp_pattern[i].chars().collect::<Vec<char>>();
-            let mut ch = row[j];
-            ch = if ch == '.' { '#' } else { '.' };
-            row[j] = ch;
-            temp_pattern[i] = row.iter().collect::<String>();
+            let ch = &mut temp_pattern[(i, j)];
+            *ch = if *ch == '.' { '#' } else { '.' };
             let (temp_r, temp_c) = find_reflection_in(&temp_pattern);
             if temp_r.is_some() {
                 if r.is_some() && temp_r.unwrap() < r.unwrap() {
@@ -149,9 +143,7 @@ fn find_reflection_in_modified(pattern: &Pattern) -> (Option<usize>, Option<usiz
 
 // Calculate a "summary" value based on the number of reflected columns and rows
 // in the input patterns
-fn solve_part1(input: &String) -> String {
-    let patterns = parse_patterns_from(input);
-
+fn solve_part1(patterns: &Patterns) -> String {
     let mut col_count = 0;
     let mut row_count = 0;
     for pattern in patterns.iter() {
@@ -163,9 +155,7 @@ fn solve_part1(input: &String) -> String {
     (col_count + 100 * row_count).to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    let patterns = parse_patterns_from(input);
-
+fn solve_part2(patterns: &Patterns) -> String {
     let mut col_count = 0;
     let mut row_count = 0;
     for pattern in patterns.iter() {
@@ -179,11 +169,7 @@ fn solve_part2(input: &String) -> String {
         }
     }
 
-    let output = col_count + 100 * row_count;
-    assert!(output != 21700);
-    assert!(output != 23947);
-    assert!(output != 43007);
-    output.to_string()
+    (col_count + 100 * row_count).to_string()
 }
 
 #[cfg(test)]
@@ -208,8 +194,12 @@ mod test {
 #....#..#
         "];
 
-    fn get_input(ix: usize) -> String {
-        String::from(INPUT[ix].trim())
+    fn get_input(ix: usize) -> Patterns {
+        Day13::parse(INPUT[ix])
+    }
+
+    fn pattern_from(rows: &[&str]) -> Pattern {
+        rows.join("\n").parse().unwrap()
     }
 
     #[test]
@@ -224,98 +214,75 @@ mod test {
 
     #[test]
     fn test_rotate() {
-        let pattern = vec!["#.##.".to_string(), "#..##".to_string()];
-        let expected_rotated = vec![
-            "##".to_string(),
-            "..".to_string(),
-            ".#".to_string(),
-            "##".to_string(),
-            "#.".to_string(),
-        ];
-        let actual_rotated = rotate(&pattern);
+        let pattern = pattern_from(&["#.##.", "#..##"]);
+        let expected_rotated = pattern_from(&["##", "..", ".#", "##", "#."]);
+        let actual_rotated = pattern.rotate_clockwise();
 
         assert_eq!(actual_rotated, expected_rotated);
-        let pattern = vec![
-            "#.##.".to_string(),
-            "#..##".to_string(),
-            "#..#.".to_string(),
-        ];
-        let expected_rotated = vec![
-            "###".to_string(),
-            "...".to_string(),
-            "..#".to_string(),
-            "###".to_string(),
-            ".#.".to_string(),
-        ];
-        let actual_rotated = rotate(&pattern);
+        let pattern = pattern_from(&["#.##.", "#..##", "#..#."]);
+        let expected_rotated = pattern_from(&["###", "...", "..#", "###", ".#."]);
+        let actual_rotated = pattern.rotate_clockwise();
         assert_eq!(actual_rotated, expected_rotated);
 
-        let pattern = vec![
-            "#.##..##.".to_string(),
-            "..#.##.#.".to_string(),
-            "##......#".to_string(),
-            "##......#".to_string(),
-            "..#.##.#.".to_string(),
-            "..##..##.".to_string(),
-            "#.#.##.#.".to_string(),
-        ];
-        let expected_rotated = vec![
-            "#..##.#".to_string(),
-            "...##..".to_string(),
-            "###..##".to_string(),
-            ".#....#".to_string(),
-            "#.#..#.".to_string(),
-            "#.#..#.".to_string(),
-            ".#....#".to_string(),
-            "###..##".to_string(),
-            "...##..".to_string(),
-        ];
-        let actual_rotated = rotate(&pattern);
+        let pattern = pattern_from(&[
+            "#.##..##.",
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+        ]);
+        let expected_rotated = pattern_from(&[
+            "#..##.#", "...##..", "###..##", ".#....#", "#.#..#.", "#.#..#.", ".#....#", "###..##",
+            "...##..",
+        ]);
+        let actual_rotated = pattern.rotate_clockwise();
         assert_eq!(actual_rotated, expected_rotated);
     }
 
     #[test]
     fn test_find_reflection() {
-        let pattern = vec!["##......#".to_string(), "##......#".to_string()];
+        let pattern = pattern_from(&["##......#", "##......#"]);
         let (actual_r, actual_c) = find_reflection_in(&pattern);
         assert_eq!(actual_r, Some(1));
         assert_eq!(actual_c, None);
 
-        let pattern = vec![
-            "..#.##.#.".to_string(),
-            "##......#".to_string(),
-            "##......#".to_string(),
-            "..#.##.#.".to_string(),
-            "..##..##.".to_string(),
-        ];
+        let pattern = pattern_from(&[
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+        ]);
         let (actual_r, actual_c) = find_reflection_in(&pattern);
         assert_eq!(actual_r, Some(2));
         assert_eq!(actual_c, None);
 
-        let pattern = vec![
-            "##......#".to_string(),
-            "##......#".to_string(),
-            "#.##..##.".to_string(),
-            "..#.##.#.".to_string(),
-            "..#.##.#.".to_string(),
-            "..##..##.".to_string(),
-            "#.#.##.#.".to_string(),
-        ];
+        let pattern = pattern_from(&[
+            "##......#",
+            "##......#",
+            "#.##..##.",
+            "..#.##.#.",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+        ]);
         let (actual_r, actual_c) = find_reflection_in(&pattern);
         assert_eq!(actual_r, Some(1));
         assert_eq!(actual_c, None);
 
-        let pattern = vec![
-            "#.##..##.".to_string(),
-            "..#.##.#.".to_string(),
-            "##......#".to_string(),
-            "##......#".to_string(),
-            "..#.##.#.".to_string(),
-            "..##..##.".to_string(),
-            "#.#.##.#.".to_string(),
-        ];
-
-        let rotated = rotate(&pattern);
+        let pattern = pattern_from(&[
+            "#.##..##.",
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+        ]);
+
+        let rotated = pattern.rotate_clockwise();
         let (actual_r, actual_c) = find_reflection_in(&rotated);
         assert_eq!(actual_r, Some(5));
         assert_eq!(actual_c, None);
@@ -324,8 +291,7 @@ mod test {
         assert_eq!(actual_r, None);
         assert_eq!(actual_c, Some(5));
 
-        let input = get_input(0);
-        let patterns = parse_patterns_from(&input);
+        let patterns = get_input(0);
         let expected_reflections = vec![(None, Some(5)), (Some(4), None)];
         for (pattern, expected) in patterns.iter().zip(expected_reflections.iter()) {
             let (actual_r, actual_c) = find_reflection_in(pattern);
@@ -1,41 +1,54 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
-
-pub fn run() {
-    println!("Day 14 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(14);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::solution::{PartStatus, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Parabolic Reflector Dish";
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Unsolved];
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
-fn calc_load_on(platform: &Vec<Vec<char>>) -> i32 {
-    (0..platform.len())
-        .map(|i| platform[i] 
-            .iter()
-            .filter(|&p| *p == 'O')
-            .fold(0, |acc, _| acc + platform.len() - i))
+fn calc_load_on(platform: &Grid<char>) -> i32 {
+    platform
+        .iter_rows()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .filter(|&p| *p == 'O')
+                .fold(0, |acc, _| acc + platform.rows() - i)
+        })
         .fold(0, |acc, p| acc + p) as i32
 }
 
-fn solve_part1(input: &String) -> String {
-    let mut platform = input
-        .split('\n')
-        .map(|row| row.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    let n_cols = platform[0].len();
+fn solve_part1(input: &Grid<char>) -> String {
+    let mut platform = input.clone();
     let mut rolling = true;
     while rolling {
         rolling = false;
-        for i in 1..platform.len() {
-            for j in 0..n_cols {
-                if platform[i - 1][j] == '.' && platform[i][j] == 'O' {
-                    platform[i - 1][j] = platform[i][j];
-                    platform[i][j] = '.';
+        for i in 1..platform.rows() {
+            for j in 0..platform.cols() {
+                if platform[(i - 1, j)] == '.' && platform[(i, j)] == 'O' {
+                    platform[(i - 1, j)] = platform[(i, j)];
+                    platform[(i, j)] = '.';
                     rolling = true;
                 }
             }
@@ -47,8 +60,8 @@ fn solve_part1(input: &String) -> String {
     load.to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    String::new()
+fn solve_part2(input: &Grid<char>) -> Answer {
+    Answer::Unsolved
 }
 
 #[cfg(test)]
@@ -67,8 +80,8 @@ O.#..O.#.#
 #....###..
 #OO..#...."];
 
-    fn get_input(ix: usize) -> String {
-        String::from(INPUT[ix].trim())
+    fn get_input(ix: usize) -> Grid<char> {
+        Day14::parse(INPUT[ix])
     }
 
     #[test]
@@ -78,6 +91,6 @@ O.#..O.#.#
 
     #[test]
     fn test_full_part2() {
-        assert_eq!(solve_part2(&get_input(0)), "");
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
     }
 }
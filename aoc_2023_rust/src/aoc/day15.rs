@@ -1,16 +1,29 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
-
-pub fn run() {
-    println!("Day 15 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(15);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+use crate::aoc::answer::Answer;
+use crate::aoc::solution::{PartStatus, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Lens Library";
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Unsolved];
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
 fn hash(s: &str) -> u32 {
@@ -35,8 +48,8 @@ fn solve_part1(input: &String) -> String {
         .to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    String::new()
+fn solve_part2(input: &String) -> Answer {
+    Answer::Unsolved
 }
 
 #[cfg(test)]
@@ -61,6 +74,6 @@ mod test {
 
     #[test]
     fn test_full_part2() {
-        assert_eq!(solve_part2(&get_input(0)), "");
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
     }
 }
@@ -1,43 +1,36 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::Grid;
+use crate::aoc::point::{Direction, Point};
+use crate::aoc::search;
+use crate::aoc::solution::{PartStatus, Solution};
 use std::collections::HashSet;
 
-pub fn run() {
-    println!("Day 16 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(16);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
-}
+pub struct Day16;
 
-#[derive(Debug)]
-struct Tile {
-    ch: char,
-    energized: bool,
-}
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "The Floor Will Be Lava";
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Unsolved];
 
-impl Tile {
-    fn new(ch: char) -> Self {
-        Self {
-            ch: ch,
-            energized: false,
-        }
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
     }
-}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Direction {
-    Right,
-    Down,
-    Left,
-    Up,
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
-type Coordinate = (isize, isize);
+type Coordinate = Point;
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 struct Beam {
     coor: Coordinate,
@@ -54,134 +47,49 @@ impl Beam {
         }
     }
 
-    fn go(&self, dir: Direction) -> Self {
-        let coor = match dir {
-            Direction::Right => (self.coor.0, self.coor.1 + 1),
-            Direction::Down => (self.coor.0 + 1, self.coor.1),
-            Direction::Left => (self.coor.0, self.coor.1 - 1),
-            Direction::Up => (self.coor.0 - 1, self.coor.1),
-        };
+    // The beam one step further in `dir`, unless that leaves the grid
+    fn go(&self, dir: Direction, grid: &Grid<char>) -> Option<Self> {
+        let coor = self.coor.step(dir);
+        if !grid.contains_point(coor) {
+            return None;
+        }
         let mut next = Self::new(coor, dir);
         next.prev_coor = Some(self.coor);
-        next
+        Some(next)
     }
 }
 
-fn solve_part1(input: &String) -> String {
-    let mut grid = input
-        .split('\n')
-        .map(|row| row.chars().map(|ch| Tile::new(ch)).collect::<Vec<Tile>>())
-        .collect::<Vec<Vec<Tile>>>();
-    let mut beams = Vec::<Beam>::new();
-    let beam = Beam::new((0, 0), Direction::Right);
-    beams.push(beam);
-
-    let mut visited = HashSet::<Beam>::new();
-    while beams.len() > 0 {
-        let beam = beams.remove(0);
-        let (r, c) = beam.coor;
-        if r < 0 || r >= grid.len() as isize || c < 0 || c >= grid[0].len() as isize {
-            continue;
-        }
-        let (r, c) = (r as usize, c as usize);
-        let tile = &mut grid[r][c];
-        tile.energized = true;
-
-        match tile.ch {
-            '.' => {
-                let next = beam.go(beam.dir);
-                if !visited.contains(&next) {
-                    beams.push(next);
-                    visited.insert(next.clone());
-                }
-            }
-            '\\' => {
-                let next = beam.go(match beam.dir {
-                    Direction::Right => Direction::Down,
-                    Direction::Down => Direction::Right,
-                    Direction::Left => Direction::Up,
-                    Direction::Up => Direction::Left,
-                });
-                if !visited.contains(&next) {
-                    beams.push(next);
-                    visited.insert(next.clone());
-                }
-            }
-            '|' => {
-                match beam.dir {
-                    Direction::Right | Direction::Left => {
-                        let next = beam.go(Direction::Up);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                        let next = beam.go(Direction::Down);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                    }
-                    direction => {
-                        let next = beam.go(direction);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                    }
-                };
-            }
-            '-' => {
-                match beam.dir {
-                    Direction::Down | Direction::Up => {
-                        let next = beam.go(Direction::Left);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                        let next = beam.go(Direction::Right);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                    }
-                    direction => {
-                        let next = beam.go(direction);
-                        if !visited.contains(&next) {
-                            beams.push(next);
-                            visited.insert(next.clone());
-                        }
-                    }
-                };
-            }
-            '/' => {
-                let next = beam.go(match beam.dir {
-                    Direction::Right => Direction::Up,
-                    Direction::Down => Direction::Left,
-                    Direction::Left => Direction::Down,
-                    Direction::Up => Direction::Right,
-                });
-                if !visited.contains(&next) {
-                    beams.push(next);
-                    visited.insert(next.clone());
-                }
-            }
-            _ => panic!("Unknown tile char {}", tile.ch),
-        }
-    }
+// Where a beam goes after passing through the tile it is on
+fn next_beams(beam: &Beam, grid: &Grid<char>) -> Vec<Beam> {
+    let tile = grid[beam.coor];
+    let dirs = match tile {
+        '.' => vec![beam.dir],
+        '\\' if beam.dir.is_horizontal() => vec![beam.dir.turn_right()],
+        '\\' => vec![beam.dir.turn_left()],
+        '/' if beam.dir.is_horizontal() => vec![beam.dir.turn_left()],
+        '/' => vec![beam.dir.turn_right()],
+        '|' if beam.dir.is_horizontal() => vec![Direction::Up, Direction::Down],
+        '-' if beam.dir.is_vertical() => vec![Direction::Left, Direction::Right],
+        '|' | '-' => vec![beam.dir],
+        _ => panic!("Unknown tile char {}", tile),
+    };
+    dirs.into_iter()
+        .filter_map(|dir| beam.go(dir, grid))
+        .collect()
+}
 
-    grid.iter()
-        .map(|row| {
-            row.iter()
-                .filter(|t| t.energized)
-                .collect::<Vec<&Tile>>()
-                .len()
-        })
-        .fold(0, |acc, len| acc + len)
+fn solve_part1(input: &Grid<char>) -> String {
+    let beam = Beam::new(Point::ORIGIN, Direction::Right);
+    search::bfs_distances([beam], |beam| next_beams(beam, input))
+        .into_keys()
+        .map(|beam| beam.coor)
+        .collect::<HashSet<Coordinate>>()
+        .len()
         .to_string()
 }
 
-fn solve_part2(input: &String) -> String {
-    String::new()
+fn solve_part2(input: &Grid<char>) -> Answer {
+    Answer::Unsolved
 }
 
 #[cfg(test)]
@@ -199,8 +107,8 @@ mod test {
 .-.-/..|..
 .|....-|.\
 ..//.|...."];
-    fn get_input(ix: usize) -> String {
-        String::from(INPUT[ix].trim())
+    fn get_input(ix: usize) -> Grid<char> {
+        Day16::parse(INPUT[ix])
     }
 
     #[test]
@@ -211,6 +119,6 @@ mod test {
     #[test]
     #[ignore]
     fn test_full_part2() {
-        assert_eq!(solve_part2(&get_input(0)), "");
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
     }
 }
@@ -1,14 +1,26 @@
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::solution::Solution;
 use std::collections::HashMap;
 
-pub fn run() {
-    println!("Day 2 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(2);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
 // Find the sum of Game IDs whose cube counts could have come from
@@ -1,13 +1,26 @@
-use crate::aoc::input::read_input_for_day;
-
-pub fn run() {
-    println!("Day 3 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(3);
-    let part1 = solve_part1(&input);
-    let part2 = solve_part2(&input);
-    println!("\tPart1: {part1}");
-    println!("\tPart2: {part2}");
+use crate::aoc::answer::Answer;
+use crate::aoc::grid::{Grid, OFFSETS8};
+use crate::aoc::solution::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
 type Coordinate = (usize, usize);
@@ -54,28 +67,19 @@ impl Cell {
 
     fn is_neighbor_of_coor(&self, coor: Coordinate) -> bool {
         let (r, c) = coor;
-        Vec::<(isize, isize)>::from([
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ])
-        .iter()
-        .map(|(rx, cx)| {
-            (
-                r.checked_add_signed(*rx).unwrap_or(0),
-                c.checked_add_signed(*cx).unwrap_or(0),
-            )
-        })
-        // check if self.coor is in neighbors
-        .filter(|(rx, cx)| *rx == self.row && self.col_rng.0 <= *cx && self.col_rng.1 > *cx)
-        .peekable()
-        .peek()
-        .is_some()
+        OFFSETS8
+            .iter()
+            .map(|(rx, cx)| {
+                (
+                    r.checked_add_signed(*rx).unwrap_or(0),
+                    c.checked_add_signed(*cx).unwrap_or(0),
+                )
+            })
+            // check if self.coor is in neighbors
+            .filter(|(rx, cx)| *rx == self.row && self.col_rng.0 <= *cx && self.col_rng.1 > *cx)
+            .peekable()
+            .peek()
+            .is_some()
     }
 
     fn num_val(&self) -> i32 {
@@ -83,11 +87,11 @@ impl Cell {
     }
 }
 
-fn convert_input_to_cells(input: &String) -> Vec<Cell> {
+fn convert_input_to_cells(input: &Grid<char>) -> Vec<Cell> {
     let mut cells = Vec::new();
-    for (r, line) in input.split('\n').enumerate() {
+    for (r, line) in input.iter_rows().enumerate() {
         let mut num_str = String::from("");
-        for (c, chr) in line.chars().enumerate() {
+        for (c, &chr) in line.iter().enumerate() {
             if !chr.is_digit(10) {
                 if num_str.len() > 0 {
                     cells.push(Cell::new(num_str.clone(), (r, c - num_str.len())));
@@ -111,7 +115,7 @@ fn convert_input_to_cells(input: &String) -> Vec<Cell> {
 }
 
 // Find the sum of all numbers adjacent to symbols (including diagonally!).
-fn solve_part1(input: &String) -> String {
+fn solve_part1(input: &Grid<char>) -> String {
     // Preprocess:
     //  - Parse input into `Cell`s
     // Find all symbol coordinates (r, c)
@@ -136,7 +140,7 @@ fn solve_part1(input: &String) -> String {
 
 // Find the sum of the products of numbers adjacent to all gears ("*") for
 // gears with exactly two adjacent numbers.
-fn solve_part2(input: &String) -> String {
+fn solve_part2(input: &Grid<char>) -> String {
     let cells = convert_input_to_cells(input);
     let gear_cells = cells
         .iter()
@@ -206,8 +210,8 @@ mod test {
 ",
     ];
 
-    fn get_input(ix: usize) -> String {
-        INPUT[ix].trim().to_string()
+    fn get_input(ix: usize) -> Grid<char> {
+        Day3::parse(INPUT[ix])
     }
 
     #[test]
@@ -231,7 +235,7 @@ mod test {
 
     #[test]
     fn test_convert_input_to_cells() {
-        let input = String::from("467..*");
+        let input = Day3::parse("467..*");
         let expected_cells = vec![
             Cell::new("467".to_string(), (0, 0)),
             Cell::new("*".to_string(), (0, 5)),
@@ -242,7 +246,7 @@ mod test {
             assert_eq!(a, b);
         }
 
-        let input = String::from("229*952");
+        let input = Day3::parse("229*952");
         let expected_cells = vec![
             Cell::new("229".to_string(), (0, 0)),
             Cell::new("*".to_string(), (0, 3)),
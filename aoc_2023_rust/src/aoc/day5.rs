@@ -2,18 +2,30 @@
 #![allow(unused_variables)]
 #![allow(unused_mut)]
 
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::interval::{Interval, OffsetMap, RangeSet};
+use crate::aoc::solution::Solution;
 use std::collections::HashMap;
 
-pub fn run() {
-    println!("Day 5 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(5);
-    let part1 = solve_part1(&input);
-    println!("\tPart1: {part1}");
-    println!("Skipping day 5 part 2 for now...");
-    //let part2 = solve_part2(&input);
-    //println!("\tPart2: {part2}");
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
 }
 
 type Num = i64;
@@ -32,6 +44,14 @@ fn parse_seeds_from(input: &String) -> Seeds {
         .collect::<Seeds>()
 }
 
+// The seeds line read as pairs of a starting seed and how many seeds follow it
+fn parse_seed_ranges_from(input: &String) -> RangeSet {
+    parse_seeds_from(input)
+        .chunks(2)
+        .map(|pair| Interval::with_len(pair[0], pair[1]))
+        .collect()
+}
+
 #[derive(Debug)]
 struct Mapping {
     src: Num,
@@ -67,6 +87,14 @@ impl Map {
             mappings: mappings,
         }
     }
+
+    fn offsets(&self) -> OffsetMap {
+        let mut offsets = OffsetMap::new();
+        for &Mapping { src, dst, rng } in self.mappings.iter() {
+            offsets.add(Interval::with_len(src, rng), dst - src);
+        }
+        offsets
+    }
 }
 
 fn parse_map_from(input: &String) -> Map {
@@ -120,9 +148,6 @@ fn calc_src_for_dst(dst_name: &str, dst_value: Num, maps: &HashMap<String, Map>)
     (map.src_name.clone(), value)
 }
 
-// TODO: Basically, check if the seed value could come from any of the locations.
-//       - If so, then perform some sort of search (binary?) to find the smallest location?
-
 fn calc_location_for_seed(seed: Num, maps: &HashMap<String, Map>) -> Num {
     let mut src_name = String::from("seed");
     let mut src_value = seed;
@@ -161,33 +186,29 @@ fn is_valid_seed(seed: Num, all_seeds: &Vec<Num>) -> bool {
     false
 }
 
-fn calc_location_rng_for_seed_rng(
-    seed: Num,
-    seed_rng: Num,
-    maps: &HashMap<String, Map>,
-) -> (Num, Num) {
-    (0, 0)
+fn calc_location_rng_for_seed_rng(seeds: &RangeSet, maps: &HashMap<String, Map>) -> RangeSet {
+    let mut src_name = String::from("seed");
+    let mut src_values = seeds.clone();
+    let location = "location".to_string();
+    while src_name != location {
+        let (dst_name, dst_values) = calc_dst_rng_for_src_rng(&src_name[..], &src_values, maps);
+        src_name = dst_name;
+        src_values = dst_values;
+    }
+
+    src_values
 }
 
+// Ranges that cross several mappings get split up between them
 fn calc_dst_rng_for_src_rng(
     src_name: &str,
-    src_value: Num,
-    src_rng: Num,
+    src_values: &RangeSet,
     maps: &HashMap<String, Map>,
-) -> (String, Num, Num) {
+) -> (String, RangeSet) {
     let map = &maps[src_name];
-    let mut value = src_value;
-    let mut rng = src_rng;
-    for mapping in map.mappings.iter() {
-        let &Mapping { src, dst, rng } = mapping;
-        if src <= src_value && src_value < (src + rng) {
-            value = dst + (src_value - src);
-        }
-    }
-    (map.dst_name.clone(), value, rng)
+    (map.dst_name.clone(), map.offsets().apply_to(src_values))
 }
 
-
 // Determine the "closest" location that needs a seed
 //  - Which basically means find the smallest location value that's found through
 //    a series of mappings in the given input string.
@@ -207,40 +228,11 @@ fn solve_part1(input: &String) -> String {
 // Determine the "closest" location that needs a seed based on a range of seed values
 fn solve_part2(input: &String) -> String {
     let input = String::from(input.trim()); // :(
-    let seeds = parse_seeds_from(&input);
+    let seeds = parse_seed_ranges_from(&input);
     let maps = parse_maps_from(&input, true);
-    println!("seeds: {:?}", seeds);
-    for i in 0..seeds.len() - 1 {
-        let (seed, seed_rng) = (seeds[i], seeds[i + 1]);
-        let (location, location_rng) = calc_location_rng_for_seed_rng(seed, seed_rng, &maps);
-        println!("{seed}, {seed_rng}");
-        println!("{location}, {location_rng}");
-    }
-
-    /*
-    let starting_seeds = seeds.iter().step_by(2);
-    let maps = parse_maps_from(&input, true);
-    let mut lowest_location = Num::MAX;
-    for seed in starting_seeds {
-        let current_location = calc_location_for_seed(*seed, &maps);
-        lowest_location = std::cmp::min(lowest_location, current_location);
-    }
+    let locations = calc_location_rng_for_seed_rng(&seeds, &maps);
 
-    let rev_maps = parse_maps_from(&input, false);
-    let mut location = lowest_location;
-    loop {
-        location -= 1;
-        let seed = calc_seed_from_location(location, &rev_maps);
-        if is_valid_seed(seed, &seeds) {
-            lowest_location = std::cmp::min(lowest_location, location);
-        }
-
-        if location == 0 { break; }
-    }
-
-    lowest_location.to_string()
-    */
-    String::new()
+    locations.min().unwrap().to_string()
 }
 
 #[cfg(test)]
@@ -414,19 +406,31 @@ humidity-to-location map:
     }
 
     #[test]
-    // fn test_calc_location_rng_from_seed_rng() {
     fn test_calc_dst_rng_for_src_rng() {
-        let seeds = parse_seeds_from(&get_input(0));
+        let seeds = parse_seed_ranges_from(&get_input(0));
         let maps = parse_maps_from(&get_input(0), true);
-        println!("seeds: {:?}", seeds);
-        for i in 0..seeds.len() - 1 {
-            let (src, src_rng) = (seeds[i], seeds[i + 1]);
-            let (dst_name, dst, dst_rng) = calc_dst_rng_for_src_rng("src", src, src_rng, &maps);
-            println!("{src}, {src_rng}");
-            println!("{dst}, {dst_rng}");
-        }
+        let (dst_name, soils) = calc_dst_rng_for_src_rng("seed", &seeds, &maps);
+        assert_eq!(dst_name, "soil");
+        let expected_soils = RangeSet::from_iter([Interval::new(81, 95), Interval::new(57, 70)]);
+        assert_eq!(soils, expected_soils);
+
+        // Seeds 96 and 97 go up by 2, seeds 98 and 99 go down to 50 and 51
+        let seeds = RangeSet::from(Interval::new(96, 100));
+        let (_, soils) = calc_dst_rng_for_src_rng("seed", &seeds, &maps);
+        let expected_soils = RangeSet::from_iter([Interval::new(50, 52), Interval::new(98, 100)]);
+        assert_eq!(soils, expected_soils);
+    }
 
-        assert!(false);
+    #[test]
+    fn test_calc_location_rng_for_seed_rng() {
+        let seeds = parse_seed_ranges_from(&get_input(0));
+        let maps = parse_maps_from(&get_input(0), true);
+        let locations = calc_location_rng_for_seed_rng(&seeds, &maps);
+        assert_eq!(locations.len(), seeds.len());
+        assert_eq!(locations.min(), Some(46));
+        for seed in [79, 82, 92, 55, 67] {
+            assert!(locations.contains(calc_location_for_seed(seed, &maps)));
+        }
     }
 
     #[test]
@@ -463,7 +467,6 @@ humidity-to-location map:
     }
 
     #[test]
-    #[ignore]
     fn test_full_part2() {
         assert_eq!(solve_part2(&get_input(0)), "46");
     }
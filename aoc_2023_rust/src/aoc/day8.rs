@@ -1,17 +1,30 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
-use crate::aoc::input::read_input_for_day;
+use crate::aoc::answer::Answer;
+use crate::aoc::math;
+use crate::aoc::solution::Solution;
 use std::collections::HashMap;
 
-pub fn run() {
-    println!("Day 8 Solutions");
-    println!("---------------");
-    let input = read_input_for_day(8);
-    let part1 = solve_part1(&input);
-    println!("\tPart1: {part1}");
-    let part2 = solve_part2(&input);
-    println!("\tPart2: {part2}");
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input)
+    }
 }
 
 type Num = i64;
@@ -62,34 +75,61 @@ fn solve_part1(input: &String) -> String {
     steps.to_string()
 }
 
-fn gcd(a: Num, b: Num) -> Num {
-    let mut mx = if a > b { a } else { b };
-    let mut mn = if a < b { a } else { b };
+// When a ghost is on a node ending in Z. It is on one after each of the
+// steps in `before`, and from `start` on, it goes around a loop of `len`
+// steps that reaches one after each step that is one of `offsets` (mod `len`).
+#[derive(Debug, PartialEq, Eq)]
+struct Cycle {
+    before: Vec<Num>,
+    start: Num,
+    len: Num,
+    offsets: Vec<Num>,
+}
 
-    let mut rem = mx % mn;
-    while rem != 0 {
-        mx = mn;
-        mn = rem;
-        rem = mx % mn;
+impl Cycle {
+    fn is_on_z(&self, steps: Num) -> bool {
+        self.before.contains(&steps)
+            || (steps >= self.start
+                && self
+                    .offsets
+                    .iter()
+                    .any(|&offset| (steps - offset).rem_euclid(self.len) == 0))
     }
-
-    mn
 }
 
-fn lcm(a: Num, b: Num) -> Num {
-    a * b / gcd(a, b)
-}
+// The ghost is in its loop once it's back on a node at the same point of
+// the instructions as before, and it only goes around that loop from then on
+fn find_cycle_of(name: &str, instructions: &Instructions, network: &Network) -> Cycle {
+    let instructions = instructions.chars().collect::<Vec<char>>();
+    let mut seen = HashMap::<(&str, usize), Num>::new();
+    let mut z_steps = Vec::new();
+    let mut node = name;
+    let mut steps = 0;
+    loop {
+        let ix = steps as usize % instructions.len();
+        if let Some(start) = seen.insert((node, ix), steps) {
+            let (before, offsets) = z_steps.into_iter().partition(|&z_step| z_step < start);
+            return Cycle {
+                before,
+                start,
+                len: steps - start,
+                offsets,
+            };
+        }
+        if node.ends_with("Z") {
+            z_steps.push(steps);
+        }
 
-fn lcms(values: &Vec<Num>) -> Num {
-    let mut output = lcm(values[0], values[1]);
-    for i in 2..values.len() {
-        output = lcm(values[i], output);
+        steps += 1;
+        node = if instructions[ix] == 'L' {
+            network[node].0
+        } else {
+            network[node].1
+        };
     }
-
-    output
 }
 
-fn solve_part2(input: &String) -> String {
+fn solve_part2(input: &String) -> Answer {
     let instructions = parse_instructions_from(input);
     let network = parse_network_from(input);
 
@@ -97,28 +137,59 @@ fn solve_part2(input: &String) -> String {
         .keys()
         .filter(|k| k.ends_with("A"))
         .collect::<Vec<&&str>>();
-    let mut steps = vec![0; node_names.len()];
-    for (i, name) in node_names.into_iter().enumerate() {
-        let mut it = instructions.chars().cycle();
-        let mut node = name;
-        while !node.ends_with("Z") {
-            steps[i] += 1;
-            node = if it.next().unwrap() == 'L' {
-                &network[node].0
+    let cycles = node_names
+        .into_iter()
+        .map(|name| find_cycle_of(name, &instructions, &network))
+        .collect::<Vec<Cycle>>();
+
+    // Until the last ghost is in its loop, they can only all be on Z nodes
+    // when that ghost reaches one on its way there
+    let before_loops = cycles
+        .iter()
+        .flat_map(|cycle| cycle.before.iter().copied())
+        .filter(|&steps| cycles.iter().all(|cycle| cycle.is_on_z(steps)))
+        .min();
+
+    // After that, picking one offset of each ghost gives congruences that
+    // the steps have to satisfy for all of them to be on those Z nodes
+    let combinations = cycles.iter().fold(vec![vec![]], |combinations, cycle| {
+        combinations
+            .iter()
+            .flat_map(|combination: &Vec<(Num, Num)>| {
+                cycle.offsets.iter().map(move |&offset| {
+                    let mut combination = combination.clone();
+                    combination.push((offset, cycle.len));
+                    combination
+                })
+            })
+            .collect()
+    });
+    let latest_start = cycles.iter().map(|cycle| cycle.start).max().unwrap_or(0);
+    let in_loops = combinations
+        .iter()
+        .filter_map(|congruences| math::crt(congruences))
+        .map(|(steps, period)| {
+            if steps < latest_start {
+                steps + (latest_start - steps + period - 1) / period * period
             } else {
-                &network[node].1
-            };
-        }
-    }
+                steps
+            }
+        })
+        .min();
 
-    lcms(&steps).to_string()
+    before_loops
+        .into_iter()
+        .chain(in_loops)
+        .min()
+        .unwrap_or_else(|| panic!("The ghosts are never all on nodes ending in Z at once"))
+        .into()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    const INPUT: [&str; 3] = [
+    const INPUT: [&str; 7] = [
         "
 RL
 
@@ -146,6 +217,54 @@ LR
 22B = (22C, 22C)
 22C = (22Z, 22Z)
 22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+        "
+L
+
+11A = (11B, XXX)
+11B = (11C, XXX)
+11C = (11Z, XXX)
+11Z = (11C, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22D, XXX)
+22D = (22Z, XXX)
+XXX = (XXX, XXX)",
+        "
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (XXX, XXX)
+33A = (XXX, XXX)
+44A = (44B, 44B)
+44B = (44Z, 44Z)
+44Z = (44B, 44B)
+XXX = (XXX, XXX)",
+        "
+L
+
+11A = (11Z, XXX)
+11Z = (XXX, XXX)
+22A = (22Z, XXX)
+22Z = (22Z, XXX)
+XXX = (XXX, XXX)",
+        "
+L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (12Z, XXX)
+12Z = (11C, XXX)
+11C = (11Z, XXX)
+22A = (22B, XXX)
+22B = (22C, XXX)
+22C = (22D, XXX)
+22D = (22Z, XXX)
+22Z = (22A, XXX)
 XXX = (XXX, XXX)",
     ];
 
@@ -153,14 +272,6 @@ XXX = (XXX, XXX)",
         String::from(INPUT[ix].trim())
     }
 
-    #[test]
-    fn test_lcm() {
-        assert_eq!(lcm(3, 4), 12);
-        assert_eq!(lcm(4, 5), 20);
-        let nums = vec![2, 3, 4, 5, 7];
-        assert_eq!(lcms(&nums), 420);
-    }
-
     #[test]
     fn test_parse_instructions() {
         let instructions = parse_instructions_from(&get_input(0));
@@ -184,6 +295,54 @@ XXX = (XXX, XXX)",
         assert_eq!(network, expected_network);
     }
 
+    fn cycle(before: &[Num], start: Num, len: Num, offsets: &[Num]) -> Cycle {
+        Cycle {
+            before: before.to_vec(),
+            start,
+            len,
+            offsets: offsets.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_find_cycle_of() {
+        let input = get_input(4);
+        let instructions = parse_instructions_from(&input);
+        let network = parse_network_from(&input);
+        assert_eq!(
+            find_cycle_of("11A", &instructions, &network),
+            cycle(&[], 1, 2, &[2])
+        );
+        // Only reaches a Z node once, before it ends up looping on XXX
+        assert_eq!(
+            find_cycle_of("22A", &instructions, &network),
+            cycle(&[1], 2, 2, &[])
+        );
+        // Never reaches one
+        assert_eq!(
+            find_cycle_of("33A", &instructions, &network),
+            cycle(&[], 1, 2, &[])
+        );
+        // Back in its loop before it reaches the Z node in it again
+        assert_eq!(
+            find_cycle_of("44A", &instructions, &network),
+            cycle(&[], 1, 2, &[2])
+        );
+
+        let input = get_input(6);
+        let instructions = parse_instructions_from(&input);
+        let network = parse_network_from(&input);
+        // Two Z nodes in its loop
+        let cycle_11 = find_cycle_of("11A", &instructions, &network);
+        assert_eq!(cycle_11, cycle(&[], 2, 3, &[2, 3]));
+        assert!(cycle_11.is_on_z(9));
+        assert!(!cycle_11.is_on_z(10));
+        assert_eq!(
+            find_cycle_of("22A", &instructions, &network),
+            cycle(&[], 0, 5, &[4])
+        );
+    }
+
     #[test]
     fn test_full_part1_0() {
         assert_eq!(solve_part1(&get_input(0)), "2");
@@ -198,4 +357,29 @@ XXX = (XXX, XXX)",
     fn test_full_part2_2() {
         assert_eq!(solve_part2(&get_input(2)), "6");
     }
+
+    #[test]
+    fn test_full_part2_3() {
+        // The ghosts first reach Z nodes after 3 and 1 steps, and then every
+        // 2 and 4 steps
+        assert_eq!(solve_part2(&get_input(3)), "5");
+    }
+
+    #[test]
+    #[should_panic(expected = "never all on nodes ending in Z")]
+    fn test_full_part2_4() {
+        solve_part2(&get_input(4));
+    }
+
+    #[test]
+    fn test_full_part2_5() {
+        // 11A only reaches a Z node once, just when 22A does
+        assert_eq!(solve_part2(&get_input(5)), "1");
+    }
+
+    #[test]
+    fn test_full_part2_6() {
+        // Only the second of the two Z nodes in the loop of 11A meets 22Z
+        assert_eq!(solve_part2(&get_input(6)), "9");
+    }
 }
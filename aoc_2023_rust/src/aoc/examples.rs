@@ -0,0 +1,345 @@
+use crate::aoc::Part;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ExamplesError {
+    Io(PathBuf, io::Error),
+    Page(String),
+    Fixture(PathBuf, String),
+}
+
+impl fmt::Display for ExamplesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExamplesError::Io(path, err) => write!(f, "Couldn't access {}: {err}", path.display()),
+            ExamplesError::Page(reason) => write!(f, "Couldn't read the puzzle page: {reason}"),
+            ExamplesError::Fixture(path, reason) => {
+                write!(
+                    f,
+                    "Couldn't update the tests in {}: {reason}",
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExamplesError {}
+
+// An example answer highlighted in the puzzle text, for the example input at `input`
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+lazy_static! {
+    static ref TITLE_PATTERN: Regex = Regex::new(r"--- Day (\d+): (.*?) ---").unwrap();
+    static ref ARTICLE_PATTERN: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref PRE_PATTERN: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref ANSWER_PATTERN: Regex = Regex::new(r"<code><em>(.*?)</em></code>").unwrap();
+    static ref TAG_PATTERN: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+fn unescape(html: &str) -> String {
+    TAG_PATTERN
+        .replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// The day and title in the heading of a puzzle page
+pub fn day_of(page: &str) -> Option<(u8, String)> {
+    let caps = TITLE_PATTERN.captures(page)?;
+    Some((caps[1].parse().ok()?, unescape(&caps[2])))
+}
+
+// The example answer of each part is the last highlighted code in the part's
+// description, and its input is the last example block before it. Part 2
+// usually reuses the example of part 1, so blocks carry over between parts.
+pub fn extract_examples(page: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut last_block = None;
+    for (article, part) in ARTICLE_PATTERN
+        .captures_iter(page)
+        .zip([Part::One, Part::Two])
+    {
+        let article = article.get(1).unwrap().as_str();
+        let Some(answer) = ANSWER_PATTERN.captures_iter(article).last() else {
+            last_block = PRE_PATTERN.captures_iter(article).last().or(last_block);
+            continue;
+        };
+        let answer_start = answer.get(0).unwrap().start();
+        let block = PRE_PATTERN
+            .captures_iter(article)
+            .take_while(|block| block.get(0).unwrap().start() < answer_start)
+            .last();
+        let block = block.or(last_block);
+        if let Some(block) = &block {
+            examples.push(Example {
+                part,
+                input: unescape(&block[1]).trim().to_string(),
+                answer: unescape(&answer[1]),
+            });
+        }
+        last_block = block;
+    }
+
+    examples
+}
+
+// Read the string literals of the `const INPUT: [&str; N] = [...]` fixture,
+// returning them with the byte range of the whole declaration.
+fn find_inputs_in(source: &str) -> Option<(Vec<String>, std::ops::Range<usize>)> {
+    let start = source.find("const INPUT: [&str;")?;
+    let open = start + source[start..].find("= [")? + 3;
+    let bytes = source.as_bytes();
+    let mut inputs = Vec::new();
+    let mut ix = open;
+    while ix < bytes.len() {
+        let rest = &source[ix..];
+        if rest.starts_with("/*") {
+            ix += rest.find("*/")? + 2;
+        } else if rest.starts_with("//") {
+            ix += rest.find('\n')?;
+        } else if let Some(raw) = rest.strip_prefix("r#\"") {
+            let end = raw.find("\"#")?;
+            inputs.push(raw[..end].to_string());
+            ix += end + 5;
+        } else if let Some(raw) = rest.strip_prefix("r\"") {
+            let end = raw.find('"')?;
+            inputs.push(raw[..end].to_string());
+            ix += end + 3;
+        } else if let Some(literal) = rest.strip_prefix('"') {
+            let mut input = String::new();
+            let mut chars = literal.char_indices();
+            let end = loop {
+                match chars.next()? {
+                    (_, '\\') => match chars.next()?.1 {
+                        'n' => input.push('\n'),
+                        't' => input.push('\t'),
+                        escaped => input.push(escaped),
+                    },
+                    (end, '"') => break end,
+                    (_, c) => input.push(c),
+                }
+            };
+            inputs.push(input);
+            ix += end + 2;
+        } else if rest.starts_with("];") {
+            return Some((inputs, start..ix + 2));
+        } else {
+            ix += rest.chars().next()?.len_utf8();
+        }
+    }
+
+    None
+}
+
+fn literal_for(input: &str) -> String {
+    if input.contains('"') {
+        format!("r#\"\n{input}\n\"#")
+    } else if input.contains('\\') {
+        format!("r\"\n{input}\n\"")
+    } else {
+        format!("\"\n{input}\n\"")
+    }
+}
+
+// Add the examples to the fixtures in the source of a day module and point
+// `test_full_part1`/`test_full_part2` at them. Existing inputs are kept, so
+// that other tests indexing into INPUT still work.
+pub fn update_fixtures(source: &str, examples: &[Example]) -> Result<String, String> {
+    let (mut inputs, range) =
+        find_inputs_in(source).ok_or("no `const INPUT: [&str; N]` fixture found")?;
+    let mut source = source.to_string();
+
+    let mut indexes = Vec::new();
+    for example in examples {
+        let ix = match inputs
+            .iter()
+            .position(|input| input.trim() == example.input)
+        {
+            Some(ix) => ix,
+            None => {
+                inputs.push(example.input.clone());
+                inputs.len() - 1
+            }
+        };
+        indexes.push(ix);
+    }
+
+    let entries = inputs
+        .iter()
+        .map(|input| format!("        {},\n", literal_for(input.trim())))
+        .collect::<String>();
+    let fixture = format!("const INPUT: [&str; {}] = [\n{entries}    ];", inputs.len());
+
+    // Update the tests first, as they come after the fixture
+    for (example, ix) in examples.iter().zip(indexes).rev() {
+        let n = match example.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+        let test = Regex::new(&format!(
+            r#"(?s)(fn test_full_part{n}\(\) \{{\s*)(?://\s*)?assert_eq!\(solve_part{n}\(&get_input\(\d+\)\), (?:".*?"|Answer::Unsolved)\);"#
+        ))
+        .unwrap();
+        let Some(caps) = test.captures(&source) else {
+            return Err(format!("no `test_full_part{n}` test found"));
+        };
+        let found = caps.get(0).unwrap().range();
+        let updated = format!(
+            "{}assert_eq!(solve_part{n}(&get_input({ix})), {:?});",
+            &caps[1], example.answer
+        );
+        source.replace_range(found, &updated);
+    }
+    source.replace_range(range, &fixture);
+
+    Ok(source)
+}
+
+// Pull the examples out of a saved puzzle page and write them into the tests
+// of the day in `source_dir`
+pub fn import_examples(source_dir: &Path, page_path: &Path) -> Result<Vec<Example>, ExamplesError> {
+    let page =
+        fs::read_to_string(page_path).map_err(|err| ExamplesError::Io(page_path.into(), err))?;
+    let (day, _) = day_of(&page).ok_or(ExamplesError::Page("no day heading found".to_string()))?;
+    let examples = extract_examples(&page);
+    if examples.is_empty() {
+        return Err(ExamplesError::Page("no example answers found".to_string()));
+    }
+
+    let path = source_dir.join(format!("day{day}.rs"));
+    let source = fs::read_to_string(&path).map_err(|err| ExamplesError::Io(path.clone(), err))?;
+    let source = update_fixtures(&source, &examples)
+        .map_err(|reason| ExamplesError::Fixture(path.clone(), reason))?;
+    fs::write(&path, source).map_err(|err| ExamplesError::Io(path, err))?;
+
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 6: Wait For It ---</h2><p>For example:</p>
+<pre><code>Time:      7  15   30
+Distance:  9  40  200
+</code></pre>
+<p>The first race lasts <code>7</code> milliseconds, so you can win in <code><em>4</em></code> ways.</p>
+<p>Multiplied together, you get <code><em>288</em></code>.</p>
+<pre><code>&lt;not the example&gt;
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>1</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now, you have to figure out how many ways there are to win this one race. In this example, there are <code><em>71503</em></code> ways.</p>
+</article>
+</main></body></html>"#;
+
+    const SOURCE: &str = r#"
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        "
+a\"b
+",
+        /*
+        "
+commented out
+",
+        */
+        r"
+c\d",
+    ];
+
+    #[test]
+    fn test_full_part1() {
+        // assert_eq!(solve_part1(&get_input(0)), "");
+    }
+
+    #[test]
+    fn test_full_part2() {
+        assert_eq!(solve_part2(&get_input(1)), Answer::Unsolved);
+    }
+}
+"#;
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(day_of(PAGE), Some((6, "Wait For It".to_string())));
+        let input = "Time:      7  15   30\nDistance:  9  40  200".to_string();
+        assert_eq!(
+            extract_examples(PAGE),
+            vec![
+                Example {
+                    part: Part::One,
+                    input: input.clone(),
+                    answer: "288".to_string(),
+                },
+                Example {
+                    part: Part::Two,
+                    input,
+                    answer: "71503".to_string(),
+                },
+            ]
+        );
+
+        // Blocks that come before the answer are the example input
+        let page = PAGE.replace(
+            "<p>Multiplied",
+            "<pre><code>1 &amp; 2</code></pre><p>Multiplied",
+        );
+        assert_eq!(extract_examples(&page)[0].input, "1 & 2");
+        assert_eq!(extract_examples(&page)[1].input, "1 & 2");
+    }
+
+    #[test]
+    fn test_find_inputs() {
+        let (inputs, range) = find_inputs_in(SOURCE).unwrap();
+        assert_eq!(inputs, vec!["\na\"b\n".to_string(), "\nc\\d".to_string()]);
+        assert!(SOURCE[range].ends_with("c\\d\",\n    ];"));
+        assert!(find_inputs_in("const INPUT: &str = \"\";").is_none());
+    }
+
+    #[test]
+    fn test_update_fixtures() {
+        let examples = [
+            Example {
+                part: Part::One,
+                input: "x\ny".to_string(),
+                answer: "42".to_string(),
+            },
+            Example {
+                part: Part::Two,
+                input: "c\\d".to_string(),
+                answer: "7".to_string(),
+            },
+        ];
+        let updated = update_fixtures(SOURCE, &examples).unwrap();
+        assert!(updated.contains("const INPUT: [&str; 3] = [\n        r#\"\na\"b\n\"#,\n        r\"\nc\\d\n\",\n        \"\nx\ny\n\",\n    ];"));
+        assert!(!updated.contains("commented out"));
+        assert!(updated.contains("assert_eq!(solve_part1(&get_input(2)), \"42\");"));
+        assert!(updated.contains("assert_eq!(solve_part2(&get_input(1)), \"7\");"));
+        assert_eq!(update_fixtures(&updated, &examples).unwrap(), updated);
+
+        assert!(update_fixtures("const INPUT: &str = \"\";", &examples).is_err());
+        let without_part2 = SOURCE.replace("test_full_part2", "test_something_else");
+        assert!(update_fixtures(&without_part2, &examples).is_err());
+    }
+}
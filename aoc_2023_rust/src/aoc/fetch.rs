@@ -0,0 +1,341 @@
+use crate::aoc::input::{input_dir, InputError};
+use crate::aoc::solutions;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(5);
+pub const USER_AGENT: &str = "github.com/jrmysvr/aoc_2023 (rust_aoc_2023)";
+
+const YEAR: u16 = 2023;
+
+#[derive(Debug)]
+pub enum FetchError {
+    NoSession,
+    Input(InputError),
+    Http(String),
+    Invalid(u8, String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession => write!(f, "AOC_SESSION_TOKEN is not set"),
+            FetchError::Input(err) => write!(f, "{err}"),
+            FetchError::Http(err) => write!(f, "Request failed: {err}"),
+            FetchError::Invalid(day, reason) => {
+                write!(f, "The server didn't send an input for day {day}: {reason}")
+            }
+            FetchError::Io(path, err) => write!(f, "Couldn't write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<InputError> for FetchError {
+    fn from(err: InputError) -> Self {
+        FetchError::Input(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+// The session cookie, accepting both "session=<token>" and a bare token
+pub fn session_cookie_from(token: &str) -> String {
+    let token = token.trim();
+    if token.starts_with("session=") {
+        token.to_string()
+    } else {
+        format!("session={token}")
+    }
+}
+
+pub fn session_from_env() -> Result<String, FetchError> {
+    match env::var("AOC_SESSION_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => Ok(session_cookie_from(&token)),
+        _ => Err(FetchError::NoSession),
+    }
+}
+
+// A client for the Advent of Code server which waits at least `delay` between
+// requests, so that fetching many days doesn't overload the server.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    delay: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String, delay: Duration) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            delay,
+            last_request: None,
+        }
+    }
+
+    pub fn url_for(&self, path: &str) -> String {
+        format!("{}/{YEAR}/day/{path}", self.base_url)
+    }
+
+    fn throttle(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.delay {
+                thread::sleep(self.delay - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    pub fn get(&mut self, path: &str) -> Result<String, FetchError> {
+        self.throttle();
+        let response = self
+            .agent
+            .get(&self.url_for(path))
+            .set("Cookie", &self.session)
+            .call();
+        read_body_of(response)
+    }
+
+    pub fn post_form(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, FetchError> {
+        self.throttle();
+        let response = self
+            .agent
+            .post(&self.url_for(path))
+            .set("Cookie", &self.session)
+            .send_form(form);
+        read_body_of(response)
+    }
+
+    // Download the input for a day into `input_dir`, unless it's already there
+    pub fn fetch_input(&mut self, day: u8, input_dir: &Path) -> Result<Fetched, FetchError> {
+        let path = input_dir.join(format!("day{day}.txt"));
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let body = self.get(&format!("{day}/input"))?;
+        validate_input(day, &body)?;
+        fs::create_dir_all(input_dir).map_err(|err| FetchError::Io(input_dir.into(), err))?;
+        // Write to a temporary file first so an interrupted fetch never looks cached
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, body).map_err(|err| FetchError::Io(partial.clone(), err))?;
+        fs::rename(&partial, &path).map_err(|err| FetchError::Io(path.clone(), err))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+// The base URL of the server, which can be pointed at a local stand-in server
+pub fn base_url_from_env() -> String {
+    env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string())
+}
+
+// Download the inputs for the selected days (all registered days if none are
+// given) into `$AOC_INPUT_DIR`. Returns false if any day couldn't be fetched.
+pub fn fetch_days(days: &[u8], base_url: &str, delay: Duration) -> bool {
+    let (session, input_dir) = match (session_from_env(), input_dir()) {
+        (Ok(session), Ok(input_dir)) => (session, input_dir),
+        (Err(err), _) => {
+            println!("{err}");
+            return false;
+        }
+        (_, Err(err)) => {
+            println!("{err}");
+            return false;
+        }
+    };
+    let days = if days.is_empty() {
+        solutions().iter().map(|solution| solution.day()).collect()
+    } else {
+        days.to_vec()
+    };
+
+    let mut client = Client::new(base_url, session, delay);
+    let mut all_fetched = true;
+    for day in days {
+        match client.fetch_input(day, &input_dir) {
+            Ok(Fetched::Cached(path)) => {
+                println!("Day {day}: already cached in {}", path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => println!("Day {day}: saved to {}", path.display()),
+            Err(err) => {
+                println!("Day {day}: {err}");
+                all_fetched = false;
+            }
+        }
+    }
+
+    all_fetched
+}
+
+fn read_body_of(response: Result<ureq::Response, ureq::Error>) -> Result<String, FetchError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| FetchError::Http(err.to_string())),
+        Err(ureq::Error::Status(code, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(FetchError::Http(format!("{code}: {}", body.trim())))
+        }
+        Err(err) => Err(FetchError::Http(err.to_string())),
+    }
+}
+
+fn validate_input(day: u8, body: &str) -> Result<(), FetchError> {
+    let trimmed = body.trim();
+    if trimmed.is_empty() {
+        return Err(FetchError::Invalid(day, "the input is empty".to_string()));
+    }
+    if trimmed.starts_with('<') || trimmed.starts_with("Please ") {
+        let reason = trimmed.lines().next().unwrap_or_default().to_string();
+        return Err(FetchError::Invalid(day, reason));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    // Serve one canned response per request on a local port and send back
+    // the request line, the cookie and the body of each request.
+    pub(crate) fn serve(
+        responses: Vec<(u16, &'static str)>,
+    ) -> (String, mpsc::Receiver<(String, String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let (mut cookie, mut length) = (String::new(), 0);
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    let (name, value) = line.split_once(':').unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = value.trim().to_string(),
+                        "content-length" => length = value.trim().parse().unwrap(),
+                        _ => (),
+                    }
+                }
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                let request_body = String::from_utf8(request_body).unwrap();
+                sender
+                    .send((request_line.trim().to_string(), cookie, request_body))
+                    .unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+
+    fn make_input_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_2023_fetch_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_session_cookie() {
+        assert_eq!(session_cookie_from("abc123"), "session=abc123");
+        assert_eq!(session_cookie_from("session=abc123\n"), "session=abc123");
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, requests) = serve(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let dir = make_input_dir("ok");
+        let mut client = Client::new(&base_url, session_cookie_from("abc"), Duration::ZERO);
+
+        let fetched = client.fetch_input(1, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("day1.txt")));
+        let (request_line, cookie, _) = requests.recv().unwrap();
+        assert_eq!(request_line, "GET /2023/day/1/input HTTP/1.1");
+        assert_eq!(cookie, "session=abc");
+        assert_eq!(
+            fs::read_to_string(dir.join("day1.txt")).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+
+        // The stub only answers once, so this must come from the cache
+        let fetched = client.fetch_input(1, &dir).unwrap();
+        assert_eq!(fetched, Fetched::Cached(dir.join("day1.txt")));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_invalid_input() {
+        let (base_url, _requests) = serve(vec![
+            (
+                200,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+        ]);
+        let dir = make_input_dir("invalid");
+        let mut client = Client::new(&base_url, session_cookie_from("abc"), Duration::ZERO);
+
+        assert!(matches!(
+            client.fetch_input(25, &dir),
+            Err(FetchError::Invalid(25, _))
+        ));
+        assert!(matches!(
+            client.fetch_input(1, &dir),
+            Err(FetchError::Http(_))
+        ));
+        assert!(!dir.join("day25.txt").exists());
+        assert!(!dir.join("day1.txt").exists());
+    }
+
+    #[test]
+    fn test_throttle() {
+        let mut client = Client::new("http://localhost", String::new(), Duration::from_millis(50));
+        let start = Instant::now();
+        client.throttle();
+        client.throttle();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
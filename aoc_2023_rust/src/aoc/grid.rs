@@ -0,0 +1,436 @@
+use crate::aoc::point::{Direction, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+// A position in a grid, as (row, column)
+pub type Pos = (usize, usize);
+
+// The (row, column) steps to all eight neighbors of a position, row by row
+pub const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "The grid has no cells"),
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {row} of the grid has {found} cells instead of {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+// A rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(GridError::Empty);
+        }
+        let n_rows = rows.len();
+        let mut cells = Vec::with_capacity(n_rows * cols);
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if cells_in_row.len() != cols {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: cols,
+                    found: cells_in_row.len(),
+                });
+            }
+            cells.extend(cells_in_row);
+        }
+
+        Ok(Self {
+            cells,
+            rows: n_rows,
+            cols,
+        })
+    }
+
+    // One row per line of `text`, with `cell` turning each char into a cell
+    pub fn parse_with(text: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::from_rows(
+            text.lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (r, c): Pos) -> bool {
+        r < self.rows && c < self.cols
+    }
+
+    // Like `contains`, for a point that can be off the grid on any side
+    pub fn contains_point(&self, point: Point) -> bool {
+        point.to_pos().is_some_and(|pos| self.contains(pos))
+    }
+
+    // The index of a position when the cells are numbered row by row
+    pub fn index_of(&self, (r, c): Pos) -> usize {
+        r * self.cols + c
+    }
+
+    pub fn pos_of(&self, index: usize) -> Pos {
+        (index / self.cols, index % self.cols)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => self.cells.get(self.index_of(pos)),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => {
+                let index = self.index_of(pos);
+                self.cells.get_mut(index)
+            }
+            false => None,
+        }
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_pos()?)
+    }
+
+    // The position `offset` away from `pos`, or None if that's off the grid
+    pub fn step(&self, (r, c): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let pos = (r.checked_add_signed(dr)?, c.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    // The neighbors above, right, below and left of `pos` that are on the grid
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir.offset()))
+    }
+
+    // Like `neighbors4`, but including the diagonal neighbors
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS8
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |index| (index / cols, index % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.cols..(r + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> {
+        self.cells[c..].iter().step_by(self.cols)
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |c| self.column(c))
+    }
+
+    // The first position, row by row, whose cell matches
+    pub fn find(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| matches(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all(&self, mut matches: impl FnMut(&T) -> bool) -> Vec<Pos> {
+        self.iter()
+            .filter(|(_, cell)| matches(cell))
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(cell).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    // Like `from_rows`, a grid needs at least one row and one column
+    pub fn filled(rows: usize, cols: usize, value: T) -> Result<Self, GridError> {
+        if rows == 0 || cols == 0 {
+            return Err(GridError::Empty);
+        }
+        Ok(Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        })
+    }
+
+    // Rows become columns, so (r, c) moves to (c, r)
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: self.iter_columns().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    // The first column, read bottom to top, becomes the first row
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            cells: (0..self.cols)
+                .flat_map(|c| (0..self.rows).rev().map(move |r| (r, c)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    // The last column, read top to bottom, becomes the first row
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self {
+            cells: (0..self.cols)
+                .rev()
+                .flat_map(|c| (0..self.rows).map(move |r| (r, c)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |ch| ch)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        assert!(self.contains(pos), "{pos:?} is outside of the grid");
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        assert!(self.contains(pos), "{pos:?} is outside of the grid");
+        let index = self.index_of(pos);
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match point.to_pos() {
+            Some(pos) => &self[pos],
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match point.to_pos() {
+            Some(pos) => &mut self[pos],
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+// One line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (r, row) in self.iter_rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse_with("12\n34", |ch| ch.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.row(1), &[3, 4]);
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn test_get_and_step() {
+        let mut grid = grid();
+        assert_eq!(grid.get((0, 2)), Some(&'c'));
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        *grid.get_mut((1, 1)).unwrap() = 'E';
+        grid[(1, 2)] = 'F';
+        assert_eq!(grid.to_string(), "abc\ndEF");
+
+        assert_eq!(grid.step((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((1, 2), (0, 1)), None);
+        assert_eq!(grid.index_of((1, 1)), 4);
+        assert_eq!(grid.pos_of(4), (1, 1));
+    }
+
+    #[test]
+    fn test_points() {
+        let mut grid = grid();
+        assert!(grid.contains_point(Point::new(1, 2)));
+        assert!(!grid.contains_point(Point::new(-1, 0)));
+        assert!(!grid.contains_point(Point::new(0, 3)));
+        assert_eq!(grid.get_point(Point::new(0, 1)), Some(&'b'));
+        assert_eq!(grid.get_point(Point::new(0, -1)), None);
+        grid[Point::new(1, 0)] = 'D';
+        assert_eq!(grid[Point::new(1, 0)], 'D');
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn test_negative_point() {
+        let _ = grid()[Point::new(-1, 0)];
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4((0, 1)).collect::<Vec<Pos>>(),
+            vec![(0, 2), (1, 1), (0, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8((0, 0)).collect::<Vec<Pos>>(),
+            vec![(0, 1), (1, 0), (1, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        assert_eq!(grid.row(0), &['a', 'b', 'c']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.iter_columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<String>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter_rows().count(), 2);
+        assert_eq!(
+            grid.positions().collect::<Vec<Pos>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.find(|&ch| ch > 'b'), Some((0, 2)));
+        assert_eq!(grid.find(|&ch| ch == 'x'), None);
+        assert_eq!(
+            grid.find_all(|&ch| "bdf".contains(ch)),
+            vec![(0, 1), (1, 0), (1, 2)]
+        );
+        assert_eq!(
+            grid.map(|ch| ch.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF"
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn test_filled() {
+        assert_eq!(Grid::filled(2, 2, 0).unwrap().to_string(), "00\n00");
+        assert_eq!(Grid::filled(2, 0, 0), Err(GridError::Empty));
+        assert_eq!(Grid::filled(0, 2, 0), Err(GridError::Empty));
+    }
+}
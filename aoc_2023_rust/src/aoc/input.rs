@@ -1,16 +1,255 @@
 use std::env;
-use std::fs::File;
-use std::io::Read;
-use std::path::Path;
-
-pub fn read_input_for_day(day_number: u8) -> String {
-    let input_dir = env::var("AOC_INPUT_DIR").unwrap();
-    let day = format!("day{}.txt", day_number);
-    let input_day_fpath = Path::new(&input_dir).join(day);
-    let file = File::open(input_day_fpath);
-    let mut contents = String::new();
-    file.expect("Oops... couldn't read this file...")
-        .read_to_string(&mut contents)
-        .unwrap();
-    contents.trim().to_string()
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum InputError {
+    DirNotSet,
+    Missing(PathBuf),
+    Empty(PathBuf),
+    NotUtf8(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::DirNotSet => {
+                write!(
+                    f,
+                    "No input directory: set input_dir in aoc.toml or AOC_INPUT_DIR"
+                )
+            }
+            InputError::Missing(path) => write!(f, "Input file {} is missing", path.display()),
+            InputError::Empty(path) => write!(f, "Input file {} is empty", path.display()),
+            InputError::NotUtf8(path) => {
+                write!(f, "Input file {} is not valid UTF-8", path.display())
+            }
+            InputError::Io(path, err) => {
+                write!(f, "Couldn't read input file {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+// Where the input of a day is read from
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // `$AOC_INPUT_DIR/dayN.txt`, or `$AOC_INPUT_DIR/$AOC_PROFILE/dayN.txt`
+    #[default]
+    Dir,
+    // `$AOC_INPUT_DIR/<profile>/dayN.txt`
+    Profile(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read_for_day(&self, day_number: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Dir => read_input_for_day(day_number),
+            InputSource::Profile(profile) => {
+                read_input_from_dir(&input_dir_for(Some(profile))?, day_number)
+            }
+            InputSource::File(path) => read_input_file(path),
+            InputSource::Stdin => {
+                let mut bytes = Vec::new();
+                io::stdin()
+                    .read_to_end(&mut bytes)
+                    .map_err(|err| InputError::Io(PathBuf::from(STDIN), err))?;
+                input_from_bytes(bytes, Path::new(STDIN))
+            }
+        }
+    }
+
+    // The directory holding the answer files that go with this input, if any
+    pub fn dir(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Dir => input_dir().ok(),
+            InputSource::Profile(profile) => input_dir_for(Some(profile)).ok(),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+}
+
+// `-` is stdin, anything else the path of an input file
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("Invalid input: expected a path or -".to_string()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+// $AOC_INPUT_DIR itself, which also holds the directories of the profiles
+pub fn base_input_dir() -> Result<PathBuf, InputError> {
+    env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .ok_or(InputError::DirNotSet)
+}
+
+pub fn input_dir() -> Result<PathBuf, InputError> {
+    input_dir_for(None)
+}
+
+// The inputs of a profile live in a directory of that name in $AOC_INPUT_DIR.
+// Without a profile, $AOC_PROFILE is used if set, and $AOC_INPUT_DIR itself
+// otherwise.
+pub fn input_dir_for(profile: Option<&str>) -> Result<PathBuf, InputError> {
+    let dir = base_input_dir()?;
+    let profile = profile
+        .map(String::from)
+        .or_else(|| env::var("AOC_PROFILE").ok())
+        .filter(|profile| !profile.is_empty());
+    Ok(match profile {
+        Some(profile) => dir.join(profile),
+        None => dir,
+    })
+}
+
+// The profiles in `input_dir`: its subdirectories holding at least one dayN.txt
+pub fn profiles_in(input_dir: &Path) -> Result<Vec<String>, InputError> {
+    let entries = fs::read_dir(input_dir).map_err(|err| InputError::Io(input_dir.into(), err))?;
+    let is_day_file = |name: &str| {
+        name.strip_prefix("day")
+            .and_then(|rest| rest.strip_suffix(".txt"))
+            .is_some_and(|day| day.parse::<u8>().is_ok())
+    };
+    let mut profiles = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter(|entry| {
+            fs::read_dir(entry.path()).is_ok_and(|mut files| {
+                files.any(|file| {
+                    file.is_ok_and(|file| file.file_name().to_str().is_some_and(is_day_file))
+                })
+            })
+        })
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect::<Vec<String>>();
+    profiles.sort();
+
+    Ok(profiles)
+}
+
+// How stdin is named in errors
+const STDIN: &str = "<stdin>";
+
+pub fn read_input_for_day(day_number: u8) -> Result<String, InputError> {
+    read_input_from_dir(&input_dir()?, day_number)
+}
+
+pub fn read_input_from_dir(input_dir: &Path, day_number: u8) -> Result<String, InputError> {
+    read_input_file(&input_dir.join(format!("day{}.txt", day_number)))
+}
+
+// Read a puzzle input from any file, trimming surrounding whitespace
+pub fn read_input_file(path: &Path) -> Result<String, InputError> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(InputError::Missing(path.into()))
+        }
+        Err(err) => return Err(InputError::Io(path.into(), err)),
+    };
+    input_from_bytes(bytes, path)
+}
+
+fn input_from_bytes(bytes: Vec<u8>, path: &Path) -> Result<String, InputError> {
+    let contents = match String::from_utf8(bytes) {
+        Ok(contents) => contents,
+        Err(_) => return Err(InputError::NotUtf8(path.into())),
+    };
+    let contents = contents.trim();
+    if contents.is_empty() {
+        return Err(InputError::Empty(path.into()));
+    }
+
+    Ok(contents.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn make_input_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_2023_input_{name}_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_read_input_from_dir() {
+        let dir = make_input_dir("ok");
+        fs::write(dir.join("day1.txt"), "\n1abc2\npqr3stu8vwx\n\n").unwrap();
+        let input = read_input_from_dir(&dir, 1).unwrap();
+        assert_eq!(input, "1abc2\npqr3stu8vwx");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_input_errors() {
+        let dir = make_input_dir("errors");
+        fs::write(dir.join("day2.txt"), " \n").unwrap();
+        fs::write(dir.join("day3.txt"), [0x66, 0xff, 0x6f]).unwrap();
+
+        assert!(matches!(
+            read_input_from_dir(&dir, 1),
+            Err(InputError::Missing(_))
+        ));
+        assert!(matches!(
+            read_input_from_dir(&dir, 2),
+            Err(InputError::Empty(_))
+        ));
+        assert!(matches!(
+            read_input_from_dir(&dir, 3),
+            Err(InputError::NotUtf8(_))
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_profiles_in() {
+        let dir = make_input_dir("profiles");
+        for (profile, file) in [
+            ("bob", "day1.txt"),
+            ("alice", "day12.txt"),
+            ("notes", "todo.txt"),
+        ] {
+            fs::create_dir_all(dir.join(profile)).unwrap();
+            fs::write(dir.join(profile).join(file), "input").unwrap();
+        }
+        fs::write(dir.join("day1.txt"), "input").unwrap();
+
+        assert_eq!(profiles_in(&dir).unwrap(), vec!["alice", "bob"]);
+        assert!(profiles_in(&dir.join("nope")).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_source() {
+        let dir = make_input_dir("source");
+        let path = dir.join("edge_case.txt");
+        fs::write(&path, "S.\n..\n").unwrap();
+
+        let source = path.to_str().unwrap().parse::<InputSource>().unwrap();
+        assert_eq!(source, InputSource::File(path.clone()));
+        assert_eq!(source.read_for_day(10).unwrap(), "S.\n..");
+        assert!(matches!(
+            InputSource::File(dir.join("nope.txt")).read_for_day(10),
+            Err(InputError::Missing(_))
+        ));
+        assert_eq!("-".parse::<InputSource>(), Ok(InputSource::Stdin));
+        assert!("".parse::<InputSource>().is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
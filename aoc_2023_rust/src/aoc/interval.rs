@@ -0,0 +1,332 @@
+// The half-open range of integers start..end, so `end` itself isn't in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    // An `end` before `start` gives an empty interval at `start`
+    pub fn new(start: i64, end: i64) -> Self {
+        Self {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn len(self) -> i64 {
+        self.end - self.start
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(self, other: Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    // The parts of this interval before and after `other`
+    pub fn difference(self, other: Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![self];
+        }
+        [
+            Interval::new(self.start, other.start),
+            Interval::new(other.end, self.end),
+        ]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect()
+    }
+
+    // The parts below and from `at`
+    pub fn split_at(self, at: i64) -> (Option<Interval>, Option<Interval>) {
+        let at = at.clamp(self.start, self.end);
+        let below = Interval::new(self.start, at);
+        let above = Interval::new(at, self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    pub fn shift(self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+// A set of integers, kept as sorted intervals that don't overlap or touch
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // How many values are in the set
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .iter()
+            .any(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    // Adds an interval, merging it with the ones it overlaps or touches
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let mut merged = interval;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        for &current in self.intervals.iter() {
+            if current.end < merged.start || merged.end < current.start {
+                intervals.push(current);
+            } else {
+                merged =
+                    Interval::new(merged.start.min(current.start), merged.end.max(current.end));
+            }
+        }
+        intervals.push(merged);
+        intervals.sort_unstable();
+        self.intervals = intervals;
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(|b| a.intersection(*b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut remaining = self.intervals.clone();
+        for &cut in other.intervals.iter() {
+            remaining = remaining
+                .into_iter()
+                .flat_map(|interval| interval.difference(cut))
+                .collect();
+        }
+        remaining.into_iter().collect()
+    }
+
+    // The values below and from `at`
+    pub fn split_at(&self, at: i64) -> (RangeSet, RangeSet) {
+        let (mut below, mut above) = (RangeSet::new(), RangeSet::new());
+        for interval in self.intervals.iter() {
+            let (low, high) = interval.split_at(at);
+            below.extend(low);
+            above.extend(high);
+        }
+        (below, above)
+    }
+
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        RangeSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|interval| interval.shift(offset))
+                .collect(),
+        }
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl Extend<Interval> for RangeSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+// Shifts the values in each of its intervals by that interval's offset, and
+// leaves all other values as they are. Where intervals overlap, the one that
+// was added first applies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl OffsetMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, interval: Interval, offset: i64) {
+        self.pieces.push((interval, offset));
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        match self
+            .pieces
+            .iter()
+            .find(|(interval, _)| interval.contains(value))
+        {
+            Some((_, offset)) => value + offset,
+            None => value,
+        }
+    }
+
+    // Maps every value in `set` at once, splitting its intervals wherever
+    // they cross from one piece of the map to another
+    pub fn apply_to(&self, set: &RangeSet) -> RangeSet {
+        let mut remaining = set.clone();
+        let mut mapped = RangeSet::new();
+        for &(interval, offset) in self.pieces.iter() {
+            let piece = RangeSet::from(interval);
+            mapped = mapped.union(&remaining.intersection(&piece).shift(offset));
+            remaining = remaining.difference(&piece);
+        }
+        mapped.union(&remaining)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_intervals() {
+        let a = Interval::new(2, 8);
+        assert_eq!(a.len(), 6);
+        assert_eq!(Interval::with_len(2, 6), a);
+        assert!(Interval::new(5, 3).is_empty());
+        assert!(a.contains(2));
+        assert!(!a.contains(8));
+
+        assert_eq!(
+            a.intersection(Interval::new(5, 10)),
+            Some(Interval::new(5, 8))
+        );
+        assert_eq!(a.intersection(Interval::new(8, 10)), None);
+        assert!(!a.overlaps(Interval::new(0, 2)));
+
+        assert_eq!(
+            a.difference(Interval::new(4, 6)),
+            vec![Interval::new(2, 4), Interval::new(6, 8)]
+        );
+        assert_eq!(a.difference(Interval::new(0, 5)), vec![Interval::new(5, 8)]);
+        assert_eq!(a.difference(Interval::new(0, 10)), vec![]);
+        assert_eq!(a.difference(Interval::new(9, 10)), vec![a]);
+
+        assert_eq!(
+            a.split_at(5),
+            (Some(Interval::new(2, 5)), Some(Interval::new(5, 8)))
+        );
+        assert_eq!(a.split_at(0), (None, Some(a)));
+        assert_eq!(a.split_at(8), (Some(a), None));
+        assert_eq!(a.shift(-2), Interval::new(0, 6));
+    }
+
+    #[test]
+    fn test_merging() {
+        let merged = set(&[(5, 8), (0, 2), (7, 10), (2, 3), (12, 12)]);
+        assert_eq!(
+            merged.intervals(),
+            &[Interval::new(0, 3), Interval::new(5, 10)]
+        );
+        assert_eq!(merged.len(), 8);
+        assert_eq!((merged.min(), merged.max()), (Some(0), Some(9)));
+        assert!(merged.contains(2));
+        assert!(!merged.contains(3));
+        assert_eq!(RangeSet::new().min(), None);
+        assert!(set(&[(3, 3)]).is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 15)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 15)]));
+        assert_eq!(b.difference(&a), set(&[(5, 10)]));
+        assert_eq!(a.split_at(12), (set(&[(0, 5), (10, 12)]), set(&[(12, 15)])));
+        assert_eq!(a.shift(100), set(&[(100, 105), (110, 115)]));
+    }
+
+    #[test]
+    fn test_offset_map() {
+        let mut map = OffsetMap::new();
+        map.add(Interval::new(10, 20), 100);
+        map.add(Interval::new(20, 25), -20);
+        map.add(Interval::new(15, 30), 1000);
+        assert_eq!(map.apply(5), 5);
+        assert_eq!(map.apply(15), 115);
+        assert_eq!(map.apply(22), 2);
+        assert_eq!(map.apply(27), 1027);
+
+        assert_eq!(
+            map.apply_to(&set(&[(5, 35)])),
+            set(&[(0, 5), (5, 10), (110, 120), (1025, 1030), (30, 35)])
+        );
+        assert_eq!(map.apply_to(&RangeSet::new()), RangeSet::new());
+    }
+}
@@ -0,0 +1,199 @@
+// Number theory helpers. Everything works on i64, and results that don't fit
+// in one are None rather than a panic or a wrapped value.
+
+fn unsigned_gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// gcd(0, 0) is 0. It's unsigned, as gcd(i64::MIN, 0) doesn't fit in an i64.
+pub fn gcd(a: i64, b: i64) -> u64 {
+    unsigned_gcd(a.unsigned_abs(), b.unsigned_abs())
+}
+
+// Always non-negative
+pub fn checked_lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    let lcm = (a.unsigned_abs() / gcd(a, b)).checked_mul(b.unsigned_abs())?;
+    i64::try_from(lcm).ok()
+}
+
+// The gcd of all values, or 0 if there are none
+pub fn gcd_of(values: &[i64]) -> u64 {
+    values
+        .iter()
+        .fold(0, |acc, &value| unsigned_gcd(acc, value.unsigned_abs()))
+}
+
+// The lcm of all values, or 1 if there are none
+pub fn lcm_of(values: &[i64]) -> Option<i64> {
+    values
+        .iter()
+        .try_fold(1, |acc, &value| checked_lcm(acc, value))
+}
+
+// (g, x, y) such that a * x + b * y = g = gcd(a, b). g is unsigned like the
+// result of `gcd`, while x and y always fit in an i64.
+pub fn extended_gcd(a: i64, b: i64) -> (u64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as u64, old_x as i64, old_y as i64)
+}
+
+// x in 0..m such that a * x = 1 (mod m), if a and m are coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// base ^ exp (mod m), in 0..m
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    assert!(m > 0, "The modulus should be positive, not {m}");
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+// Solves x = r (mod m) for every (r, m) in `congruences`. The moduli don't
+// have to be coprime. Returns the smallest x >= 0 and the lcm of the moduli,
+// as every solution is x plus a multiple of it. None if the congruences
+// contradict each other, a modulus isn't positive or the lcm overflows.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(r, n) in congruences {
+        if n <= 0 {
+            return None;
+        }
+        let (r, n) = ((r as i128).rem_euclid(n as i128), n as i128);
+        // x + m * k = r (mod n)  =>  m * k = r - x (mod n)
+        let (g, inv, _) = extended_gcd(m as i64, n as i64);
+        let (g, inv) = (g as i128, inv as i128);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let k = ((r - x) / g % step * inv).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        if m > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(m);
+    }
+
+    Some((x as i64, m as i64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(checked_lcm(3, 4), Some(12));
+        assert_eq!(checked_lcm(4, 5), Some(20));
+        assert_eq!(checked_lcm(-4, 6), Some(12));
+        assert_eq!(checked_lcm(0, 6), Some(0));
+        assert_eq!(checked_lcm(i64::MAX, i64::MAX - 1), None);
+
+        // Values that only fit once they're unsigned
+        assert_eq!(gcd(i64::MIN, 0), 1 << 63);
+        assert_eq!(gcd(i64::MIN, i64::MIN), 1 << 63);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(checked_lcm(i64::MIN, 1), None);
+        assert_eq!(checked_lcm(i64::MIN, i64::MIN), None);
+        assert_eq!(checked_lcm(i64::MIN / 2, -2), Some(1 << 62));
+        assert_eq!(checked_lcm(i64::MIN, 0), Some(0));
+
+        assert_eq!(lcm_of(&[2, 3, 4, 5, 7]), Some(420));
+        assert_eq!(lcm_of(&[6]), Some(6));
+        assert_eq!(lcm_of(&[]), Some(1));
+        assert_eq!(lcm_of(&[i64::MAX, 3, 2]), None);
+        assert_eq!(gcd_of(&[12, 18, 27]), 3);
+        assert_eq!(gcd_of(&[]), 0);
+        assert_eq!(lcm_of(&[i64::MIN]), None);
+        assert_eq!(gcd_of(&[i64::MIN, 0]), 1 << 63);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [
+            (240, 46),
+            (46, 240),
+            (-7, 3),
+            (0, 5),
+            (17, 0),
+            (1 << 62, 3),
+            (i64::MIN, 0),
+            (0, i64::MIN),
+            (i64::MIN, 1),
+            (i64::MIN, i64::MIN),
+            (i64::MIN, i64::MAX),
+            (i64::MAX, i64::MAX - 1),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "{a} {b}");
+            assert_eq!(
+                a as i128 * x as i128 + b as i128 * y as i128,
+                g as i128,
+                "{a} {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse_and_pow() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(3, 0), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(5, 0, 7), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        assert_eq!(mod_pow(3, 1 << 40, 1_000_000_007), 871_990_901);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli that share factors
+        assert_eq!(crt(&[(0, 2), (0, 3)]), Some((0, 6)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[(1, i64::MAX), (0, 2)]), None);
+    }
+}
@@ -16,31 +16,166 @@ pub mod day15;
 pub mod day16;
 pub mod day17;
 pub mod day18;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod calendar;
+pub mod compare;
+pub mod config;
+pub mod examples;
+pub mod fetch;
+pub mod grid;
 pub mod input;
+pub mod interval;
+pub mod math;
+pub mod point;
+pub mod pool;
+pub mod rejected;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod verify;
 
-pub fn run_all() {
-    let days = vec![
-        day1::run,
-        day2::run,
-        day3::run,
-        day4::run,
-        day5::run,
-        day6::run,
-        day7::run,
-        day8::run,
-        day9::run,
-        day10::run,
-        day11::run,
-        day12::run,
-        day13::run,
-        day14::run,
-        day15::run,
-        day16::run,
-        day17::run,
-        day18::run,
-    ];
-
-    for day in days {
-        day();
+use solution::DynSolution;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    // No selection means both parts should run
+    pub fn is_selected_by(self, selection: Option<Part>) -> bool {
+        selection.is_none_or(|part| part == self)
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {s} (expected 1 or 2)")),
+        }
+    }
+}
+
+// Parse a day selection such as "3", "1,4,9" or "5-8"
+pub fn parse_days_from(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = Vec::<u8>::new();
+    for item in spec.split(',').filter(|s| !s.is_empty()) {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day_from(first)?, parse_day_from(last)?);
+                if first > last {
+                    return Err(format!("Invalid day range: {item}"));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day_from(item)?),
+        }
+    }
+
+    Ok(days)
+}
+
+pub fn parse_day_from(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day: {s} (expected a number from 1 to 25)")),
+    }
+}
+
+// Every solved day, in day order
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+        &day4::Day4,
+        &day5::Day5,
+        &day6::Day6,
+        &day7::Day7,
+        &day8::Day8,
+        &day9::Day9,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+        &day13::Day13,
+        &day14::Day14,
+        &day15::Day15,
+        &day16::Day16,
+        &day17::Day17,
+        &day18::Day18,
+    ]
+}
+
+pub fn find_solution(day: u8) -> Option<&'static dyn DynSolution> {
+    solutions()
+        .into_iter()
+        .find(|solution| solution.day() == day)
+}
+
+// Parse `input` and solve one part of a day, or None if the day isn't registered
+pub fn solve(day: u8, part: Part, input: &str) -> Option<answer::Answer> {
+    let solution = find_solution(day)?;
+    Some(solution.solve(part, &solution.parse(input)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days_from("3"), Ok(vec![3]));
+        assert_eq!(parse_days_from("1,4,9"), Ok(vec![1, 4, 9]));
+        assert_eq!(parse_days_from("5-8"), Ok(vec![5, 6, 7, 8]));
+        assert_eq!(parse_days_from("1,5-6"), Ok(vec![1, 5, 6]));
+        assert!(parse_days_from("8-5").is_err());
+        assert!(parse_days_from("0").is_err());
+        assert!(parse_days_from("26").is_err());
+        assert!(parse_days_from("x").is_err());
+    }
+
+    #[test]
+    fn test_solutions_are_in_day_order() {
+        let days = solutions()
+            .iter()
+            .map(|solution| solution.day())
+            .collect::<Vec<u8>>();
+        let expected_days = (1..=days.len() as u8).collect::<Vec<u8>>();
+        assert_eq!(days, expected_days);
+    }
+
+    #[test]
+    fn test_find_solution() {
+        assert_eq!(find_solution(3).map(|s| s.title()), Some("Gear Ratios"));
+        assert!(find_solution(25).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!(solve(1, Part::One, input), Some(answer::Answer::Int(142)));
+        assert_eq!(solve(25, Part::One, input), None);
     }
 }
@@ -0,0 +1,262 @@
+use crate::aoc::grid::Pos;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionError(String);
+
+impl fmt::Display for DirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for DirectionError {}
+
+// One of the four directions on a grid, where up is towards row 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise, starting from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Right | Direction::Left)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    // The (row, column) step of one move in this direction
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub fn delta(self) -> Point {
+        let (row, col) = self.offset();
+        Point::new(row, col)
+    }
+}
+
+// Accepts U/R/D/L, compass points N/E/S/W and arrows like ^ > v < or ↑ → ↓ ←
+impl TryFrom<char> for Direction {
+    type Error = DirectionError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'U' | 'N' | '^' | '↑' => Ok(Direction::Up),
+            'R' | 'E' | '>' | '→' => Ok(Direction::Right),
+            'D' | 'S' | 'v' | '↓' => Ok(Direction::Down),
+            'L' | 'W' | '<' | '←' => Ok(Direction::Left),
+            _ => Err(DirectionError(ch.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = DirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => ch.try_into().map_err(|_| DirectionError(s.to_string())),
+            _ => Err(DirectionError(s.to_string())),
+        }
+    }
+}
+
+// A position that isn't bound to a grid, so it can go negative
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.delta()
+    }
+
+    // The points above, right, below and left of this one
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    // The grid position of this point, or None if it's negative
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((row, col): Pos) -> Self {
+        Self::new(row as isize, col as isize)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(dir: Direction) -> Self {
+        dir.delta()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Self::Output {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Self::Output {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, n: isize) -> Self::Output {
+        Point::new(self.row * n, self.col * n)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_ne!(dir.opposite(), dir);
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::ORIGIN);
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert!(Direction::Left.is_horizontal());
+        assert!(Direction::Down.is_vertical());
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        for (s, expected) in [
+            ("U", Direction::Up),
+            ("N", Direction::Up),
+            ("^", Direction::Up),
+            ("→", Direction::Right),
+            ("E", Direction::Right),
+            ("v", Direction::Down),
+            ("S", Direction::Down),
+            ("W", Direction::Left),
+            ("<", Direction::Left),
+        ] {
+            assert_eq!(s.parse::<Direction>(), Ok(expected), "{s}");
+        }
+        assert_eq!(Direction::try_from('D'), Ok(Direction::Down));
+        assert_eq!(
+            "X".parse::<Direction>(),
+            Err(DirectionError("X".to_string()))
+        );
+        assert!("UD".parse::<Direction>().is_err());
+        assert!("".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_points() {
+        let point = Point::new(2, -3);
+        assert_eq!(point + Point::new(1, 1), Point::new(3, -2));
+        assert_eq!(point - Point::new(1, 1), Point::new(1, -4));
+        assert_eq!(point * 2, Point::new(4, -6));
+        assert_eq!(-point, Point::new(-2, 3));
+        assert_eq!(point.step(Direction::Up), Point::new(1, -3));
+        assert_eq!(point.manhattan_distance(Point::ORIGIN), 5);
+        assert_eq!(point.to_pos(), None);
+        assert_eq!(Point::new(2, 3).to_pos(), Some((2, 3)));
+        assert_eq!(Point::from((2, 3)), Point::new(2, 3));
+        assert_eq!(
+            Point::ORIGIN.neighbors4(),
+            [
+                Point::new(-1, 0),
+                Point::new(0, 1),
+                Point::new(1, 0),
+                Point::new(0, -1)
+            ]
+        );
+
+        let mut point = Point::ORIGIN;
+        point += Direction::Down.delta() * 3;
+        point -= Point::from(Direction::Left);
+        assert_eq!(point, Point::new(3, 1));
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+// A fixed number of worker threads taking jobs from a shared queue. Dropping
+// the pool waits for the queued jobs to finish.
+pub struct ThreadPool {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    pub fn new(size: usize, stack_size: usize) -> Self {
+        assert!(size > 0, "A thread pool needs at least one worker");
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|ix| {
+                let receiver = Arc::clone(&receiver);
+                thread::Builder::new()
+                    .name(format!("worker-{ix}"))
+                    .stack_size(stack_size)
+                    .spawn(move || loop {
+                        // The lock is only held while waiting for the next job
+                        let job = receiver.lock().unwrap().recv();
+                        match job {
+                            Ok(job) => job(),
+                            Err(_) => break,
+                        }
+                    })
+                    .expect("Couldn't start a worker thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    pub fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        if let Some(sender) = &self.sender {
+            sender.send(Box::new(job)).unwrap();
+        }
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Closing the queue stops the workers once it's empty
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_thread_pool() {
+        let (sender, receiver) = mpsc::channel();
+        let start = Instant::now();
+        {
+            let pool = ThreadPool::new(4, 1024 * 1024);
+            for ix in 0..8 {
+                let sender = sender.clone();
+                pool.execute(move || {
+                    thread::sleep(Duration::from_millis(50));
+                    sender.send(ix).unwrap();
+                });
+            }
+        }
+        // Eight jobs of 50ms on four workers take about 100ms, not 400ms
+        assert!(start.elapsed() < Duration::from_millis(300));
+        drop(sender);
+        let mut done = receiver.iter().collect::<Vec<usize>>();
+        done.sort_unstable();
+        assert_eq!(done, (0..8).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::aoc::answers::{parse_day_key, parse_part_key, AnswersError};
+use crate::aoc::Part;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use toml::{Table, Value};
+
+pub const REJECTED_FILE: &str = "rejected.toml";
+
+const HEADER: &str = "\
+# Answers that were submitted and rejected, checked by the runner on every run.
+# Entries are plain answers or `{ answer = ..., hint = \"too high\" | \"too low\" }`.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hint::TooHigh => f.pad("too high"),
+            Hint::TooLow => f.pad("too low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Warning {
+    // The exact answer was already submitted and rejected
+    Rejected(Option<Hint>),
+    // The answer is at least as high as an answer that was too high
+    TooHigh { limit: String },
+    // The answer is at most as low as an answer that was too low
+    TooLow { limit: String },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::Rejected(Some(hint)) => write!(f, "was already rejected ({hint})"),
+            Warning::Rejected(None) => write!(f, "was already rejected"),
+            Warning::TooHigh { limit } => write!(f, "must be wrong, {limit} was already too high"),
+            Warning::TooLow { limit } => write!(f, "must be wrong, {limit} was already too low"),
+        }
+    }
+}
+
+// Answers that were submitted and rejected, stored as
+//
+//   [day13]
+//   part2 = ["21700", { answer = "43007", hint = "too high" }]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Rejected {
+    rejected: BTreeMap<(u8, Part), Vec<Rejection>>,
+}
+
+impl Rejected {
+    // A missing file just means that no answers have been rejected yet
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Self::parse(&text).map_err(|err| AnswersError::Parse(path.to_path_buf(), err))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text
+            .parse::<Table>()
+            .map_err(|err| err.message().to_string())?;
+        let mut rejected = Self::default();
+        for (day_key, parts) in table.iter() {
+            let day = parse_day_key(day_key)?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("[{day_key}] should be a table"))?;
+            for (part_key, values) in parts.iter() {
+                let part = parse_part_key(part_key)?;
+                let values = values
+                    .as_array()
+                    .ok_or(format!("{day_key}.{part_key} should be an array"))?;
+                for value in values {
+                    let rejection = parse_rejection_from(value)
+                        .ok_or(format!("Invalid rejected answer in {day_key}.{part_key}"))?;
+                    rejected.add(day, part, rejection);
+                }
+            }
+        }
+
+        Ok(rejected)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        fs::write(path, self.to_string()).map_err(|err| AnswersError::Io(path.to_path_buf(), err))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> &[Rejection] {
+        self.rejected
+            .get(&(day, part))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn add(&mut self, day: u8, part: Part, rejection: Rejection) {
+        self.rejected
+            .entry((day, part))
+            .or_default()
+            .push(rejection);
+    }
+
+    // Check an answer against the rejected answers, using the hints to catch
+    // answers that were never submitted but can't be right either.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Option<Warning> {
+        let rejections = self.get(day, part);
+        if let Some(rejection) = rejections.iter().find(|r| r.answer == answer) {
+            return Some(Warning::Rejected(rejection.hint));
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        for rejection in rejections {
+            let Ok(limit) = rejection.answer.parse::<i128>() else {
+                continue;
+            };
+            match rejection.hint {
+                Some(Hint::TooHigh) if value >= limit => {
+                    return Some(Warning::TooHigh {
+                        limit: rejection.answer.clone(),
+                    })
+                }
+                Some(Hint::TooLow) if value <= limit => {
+                    return Some(Warning::TooLow {
+                        limit: rejection.answer.clone(),
+                    })
+                }
+                _ => (),
+            }
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        let mut current_day = None;
+        for ((day, part), rejections) in self.rejected.iter() {
+            if current_day != Some(day) {
+                writeln!(f)?;
+                writeln!(f, "[day{day}]")?;
+                current_day = Some(day);
+            }
+            let values = rejections
+                .iter()
+                .map(|rejection| {
+                    let answer = Value::String(rejection.answer.clone());
+                    match rejection.hint {
+                        Some(hint) => format!("{{ answer = {answer}, hint = \"{hint}\" }}"),
+                        None => answer.to_string(),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            writeln!(f, "part{part} = [{values}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_answer_from(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Integer(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn parse_rejection_from(value: &Value) -> Option<Rejection> {
+    if let Some(answer) = parse_answer_from(value) {
+        return Some(Rejection { answer, hint: None });
+    }
+
+    let table = value.as_table()?;
+    let answer = parse_answer_from(table.get("answer")?)?;
+    let hint = match table.get("hint").map(Value::as_str) {
+        None => None,
+        Some(Some("too high")) => Some(Hint::TooHigh),
+        Some(Some("too low")) => Some(Hint::TooLow),
+        Some(_) => return None,
+    };
+    Some(Rejection { answer, hint })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const REJECTED: &str = r#"
+[day13]
+part2 = [21700, "23947", { answer = "43007", hint = "too high" }, { answer = 100, hint = "too low" }]
+"#;
+
+    #[test]
+    fn test_parse_rejected() {
+        let rejected = Rejected::parse(REJECTED).unwrap();
+        let rejections = rejected.get(13, Part::Two);
+        assert_eq!(rejections.len(), 4);
+        assert_eq!(rejections[0].answer, "21700");
+        assert_eq!(rejections[0].hint, None);
+        assert_eq!(rejections[2].hint, Some(Hint::TooHigh));
+        assert_eq!(rejections[3].answer, "100");
+        assert_eq!(rejections[3].hint, Some(Hint::TooLow));
+        assert!(rejected.get(13, Part::One).is_empty());
+
+        assert!(Rejected::parse("[day1]\npart1 = \"1\"").is_err());
+        assert!(Rejected::parse("[day1]\npart1 = [{ answer = 1, hint = \"close\" }]").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let rejected = Rejected::parse(REJECTED).unwrap();
+        assert_eq!(
+            rejected.check(13, Part::Two, "21700"),
+            Some(Warning::Rejected(None))
+        );
+        assert_eq!(
+            rejected.check(13, Part::Two, "43007"),
+            Some(Warning::Rejected(Some(Hint::TooHigh)))
+        );
+        assert_eq!(
+            rejected.check(13, Part::Two, "50000"),
+            Some(Warning::TooHigh {
+                limit: "43007".to_string()
+            })
+        );
+        assert_eq!(
+            rejected.check(13, Part::Two, "99"),
+            Some(Warning::TooLow {
+                limit: "100".to_string()
+            })
+        );
+        assert_eq!(rejected.check(13, Part::Two, "30000"), None);
+        assert_eq!(rejected.check(13, Part::Two, "abc"), None);
+        assert_eq!(rejected.check(13, Part::One, "21700"), None);
+    }
+
+    #[test]
+    fn test_rejected_round_trip() {
+        let mut rejected = Rejected::parse(REJECTED).unwrap();
+        rejected.add(
+            2,
+            Part::One,
+            Rejection {
+                answer: "8".to_string(),
+                hint: Some(Hint::TooLow),
+            },
+        );
+        let text = rejected.to_string();
+        assert!(text.contains("[day2]\npart1 = [{ answer = \"8\", hint = \"too low\" }]\n"));
+        assert_eq!(Rejected::parse(&text).unwrap(), rejected);
+    }
+}
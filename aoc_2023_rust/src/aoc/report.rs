@@ -0,0 +1,331 @@
+use crate::aoc::bench::format_duration;
+use crate::aoc::Part;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Invalid format: {s} (expected json, csv or text)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    // The answer was produced, but it's known to be wrong
+    Warning,
+    // No answer could be produced
+    Error,
+    // The solver panicked
+    Failed,
+    // The solver took longer than the timeout
+    Timeout,
+    // The part isn't solved yet
+    Unsolved,
+    // The part wasn't run, as its solution is marked as skipped
+    Skipped,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => f.pad("ok"),
+            Status::Warning => f.pad("warning"),
+            Status::Error => f.pad("error"),
+            Status::Failed => f.pad("failed"),
+            Status::Timeout => f.pad("timeout"),
+            Status::Unsolved => f.pad("unsolved"),
+            Status::Skipped => f.pad("skipped"),
+        }
+    }
+}
+
+// The outcome of running one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub duration: Option<Duration>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+// The records of one day, along with what's needed to print its text banner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Option<Duration>,
+    pub error: Option<String>,
+    pub records: Vec<Record>,
+}
+
+impl DayReport {
+    pub fn to_text(&self, time: bool) -> String {
+        let mut text = format!("Day {} Solutions\n---------------\n", self.day);
+        if let Some(err) = &self.error {
+            text += &format!("\t{err}\n");
+            return text;
+        }
+        if let (true, Some(parse)) = (time, self.parse) {
+            text += &format!("\tParse: {}\n", format_duration(parse));
+        }
+        for record in self.records.iter() {
+            match (record.status, &record.error) {
+                (Status::Failed, Some(err)) => {
+                    text += &format!("\tPart{}: FAILED ({err})\n", record.part);
+                    continue;
+                }
+                (Status::Timeout, Some(err)) => {
+                    text += &format!("\tPart{}: TIMEOUT ({err})\n", record.part);
+                    continue;
+                }
+                (Status::Skipped, Some(reason)) => {
+                    text += &format!("\tPart{}: skipped ({reason})\n", record.part);
+                    continue;
+                }
+                (Status::Unsolved, _) => {
+                    text += &format!("\tPart{}: (unsolved)\n", record.part);
+                    continue;
+                }
+                _ => (),
+            }
+            let answer = record.answer.as_deref().unwrap_or_default();
+            match (time, record.duration) {
+                (true, Some(duration)) => {
+                    text += &format!(
+                        "\tPart{}: {answer} ({})\n",
+                        record.part,
+                        format_duration(duration)
+                    )
+                }
+                _ => text += &format!("\tPart{}: {answer}\n", record.part),
+            }
+            if let (Status::Warning, Some(warning)) = (record.status, &record.error) {
+                text += &format!(
+                    "\t!!! WARNING: Part{} answer {answer} {warning} !!!\n",
+                    record.part
+                );
+            }
+        }
+
+        text
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            c if (c as u32) < 0x20 => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_or_null(s: Option<&str>) -> String {
+    s.map_or("null".to_string(), json_string)
+}
+
+fn duration_ms(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1e3)
+}
+
+// A JSON array with one object per record
+pub fn to_json(records: &[Record]) -> String {
+    let objects = records
+        .iter()
+        .map(|record| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"duration_ms\": {}, \"status\": \"{}\", \"error\": {}}}",
+                record.day,
+                record.part,
+                json_or_null(record.answer.as_deref()),
+                record.duration.map_or("null".to_string(), duration_ms),
+                record.status,
+                json_or_null(record.error.as_deref()),
+            )
+        })
+        .collect::<Vec<String>>();
+    if objects.is_empty() {
+        return "[]".to_string();
+    }
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+// CSV with a header row and one row per record
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv = String::from("day,part,answer,duration_ms,status,error\n");
+    for record in records {
+        csv += &format!(
+            "{},{},{},{},{},{}\n",
+            record.day,
+            record.part,
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.duration.map(duration_ms).unwrap_or_default(),
+            record.status,
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        );
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: Part::One,
+                answer: Some("142".to_string()),
+                duration: Some(Duration::from_micros(1500)),
+                status: Status::Ok,
+                error: None,
+            },
+            Record {
+                day: 13,
+                part: Part::Two,
+                answer: Some("21700".to_string()),
+                duration: Some(Duration::from_millis(2)),
+                status: Status::Warning,
+                error: Some("was already rejected".to_string()),
+            },
+            Record {
+                day: 2,
+                part: Part::One,
+                answer: None,
+                duration: None,
+                status: Status::Error,
+                error: Some("Input file \"day2.txt\" is missing, sorry".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert_eq!("text".parse::<Format>(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_to_text() {
+        let report = DayReport {
+            day: 13,
+            parse: Some(Duration::from_nanos(500)),
+            error: None,
+            records: records()[..2].to_vec(),
+        };
+        assert_eq!(
+            report.to_text(false),
+            "Day 13 Solutions\n---------------\n\tPart1: 142\n\tPart2: 21700\n\t!!! WARNING: Part2 answer 21700 was already rejected !!!\n"
+        );
+        assert!(report
+            .to_text(true)
+            .contains("\tParse: 500ns\n\tPart1: 142 (1.50ms)\n"));
+
+        let report = DayReport {
+            day: 10,
+            parse: None,
+            error: None,
+            records: vec![Record {
+                day: 10,
+                part: Part::Two,
+                answer: None,
+                duration: Some(Duration::ZERO),
+                status: Status::Unsolved,
+                error: None,
+            }],
+        };
+        assert_eq!(
+            report.to_text(false),
+            "Day 10 Solutions\n---------------\n\tPart2: (unsolved)\n"
+        );
+
+        let report = DayReport {
+            day: 5,
+            parse: None,
+            error: None,
+            records: vec![Record {
+                day: 5,
+                part: Part::Two,
+                answer: None,
+                duration: None,
+                status: Status::Skipped,
+                error: Some("too slow".to_string()),
+            }],
+        };
+        assert_eq!(
+            report.to_text(true),
+            "Day 5 Solutions\n---------------\n\tPart2: skipped (too slow)\n"
+        );
+
+        let report = DayReport {
+            day: 2,
+            parse: None,
+            error: Some("Input file is missing".to_string()),
+            records: records()[2..].to_vec(),
+        };
+        assert_eq!(
+            report.to_text(true),
+            "Day 2 Solutions\n---------------\n\tInput file is missing\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = to_json(&records());
+        assert!(json.starts_with("[\n  {\"day\": 1, \"part\": 1, \"answer\": \"142\", \"duration_ms\": 1.500, \"status\": \"ok\", \"error\": null},\n"));
+        assert!(json.contains("\"status\": \"warning\", \"error\": \"was already rejected\"}"));
+        assert!(json.ends_with("{\"day\": 2, \"part\": 1, \"answer\": null, \"duration_ms\": null, \"status\": \"error\", \"error\": \"Input file \\\"day2.txt\\\" is missing, sorry\"}\n]"));
+        assert_eq!(to_json(&[]), "[]");
+        assert_eq!(json_string("a\tb\u{1}"), "\"a\\tb\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&records()),
+            "day,part,answer,duration_ms,status,error\n\
+             1,1,142,1.500,ok,\n\
+             13,2,21700,2.000,warning,was already rejected\n\
+             2,1,,,error,\"Input file \"\"day2.txt\"\" is missing, sorry\"\n"
+        );
+    }
+}
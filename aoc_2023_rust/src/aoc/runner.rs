@@ -0,0 +1,640 @@
+use crate::aoc::bench::format_duration;
+use crate::aoc::input::InputSource;
+use crate::aoc::pool::ThreadPool;
+use crate::aoc::rejected::{Rejected, REJECTED_FILE};
+use crate::aoc::report::{to_csv, to_json, DayReport, Format, Record, Status};
+use crate::aoc::solution::{DynSolution, PartStatus};
+use crate::aoc::{find_solution, solutions, Part};
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RunOptions {
+    pub part: Option<Part>,
+    pub time: bool,
+    pub format: Format,
+    // How long parsing and each part may take before they're given up on. A
+    // solver that's given up on is left running in the background.
+    pub timeout: Option<Duration>,
+    // How many days to run at once, or one after another on this thread if unset
+    pub jobs: Option<usize>,
+    // Solve the two parts of a day at the same time
+    pub parallel_parts: bool,
+    // Read the input from a file or stdin instead of `$AOC_INPUT_DIR`
+    pub input: InputSource,
+}
+
+// Solve the selected parts of a day, recording the answer and status of each
+pub fn run(
+    solution: &'static dyn DynSolution,
+    options: &RunOptions,
+    rejected: &Rejected,
+) -> DayReport {
+    let day = solution.day();
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| part.is_selected_by(options.part));
+    let input = match options.input.read_for_day(day) {
+        Ok(input) => input,
+        Err(err) => {
+            return DayReport {
+                day,
+                parse: None,
+                error: Some(err.to_string()),
+                records: parts
+                    .map(|part| Record {
+                        day,
+                        part,
+                        answer: None,
+                        duration: None,
+                        status: Status::Error,
+                        error: Some(err.to_string()),
+                    })
+                    .collect(),
+            }
+        }
+    };
+    solve_day(solution, &input, options, rejected)
+}
+
+// The message a panic was started with, which is a &str or a String when it
+// comes from `panic!`, `unwrap` and friends
+pub fn panic_message_of(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+thread_local! {
+    // Whether this thread is in `catch_panic`, whose panics are reported anyway
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+// Wrap the panic hook, once, so that it stays quiet about the panics that
+// `catch_panic` reports. Panics on every other thread are still printed.
+fn install_quiet_hook() {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANICS.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+// Run `f`, turning a panic into an error with the panic message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_quiet_hook();
+    let catching = CATCHING_PANICS.with(|flag| flag.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.with(|flag| flag.set(catching));
+    result.map_err(|payload| panic_message_of(&*payload))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Interrupted {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Interrupted {
+    pub fn status(&self) -> Status {
+        match self {
+            Interrupted::Panicked(_) => Status::Failed,
+            Interrupted::TimedOut(_) => Status::Timeout,
+        }
+    }
+
+    pub fn describe(&self, step: &str) -> String {
+        match self {
+            Interrupted::Panicked(message) => format!("panicked{step}: {message}"),
+            Interrupted::TimedOut(timeout) => {
+                format!("timed out{step} after {}", format_duration(*timeout))
+            }
+        }
+    }
+}
+
+// Solvers get a bigger stack than the 2MiB of spawned threads by default, as
+// on the main thread, so that recursive solutions behave the same either way
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+// Run `f` with panics caught and, given a timeout, on a worker thread that's
+// abandoned if it doesn't finish in time. An abandoned worker keeps running in
+// the background until it's done or the process exits.
+pub fn run_isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Interrupted> {
+    let Some(timeout) = timeout else {
+        return catch_panic(f).map_err(Interrupted::Panicked);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let worker = thread::Builder::new()
+        .name("solver".to_string())
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            // The receiver is gone if the solver took too long
+            let _ = sender.send(catch_panic(f));
+        });
+    if let Err(err) = worker {
+        return Err(Interrupted::Panicked(format!(
+            "couldn't start a worker: {err}"
+        )));
+    }
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result.map_err(Interrupted::Panicked),
+        Err(RecvTimeoutError::Timeout) => Err(Interrupted::TimedOut(timeout)),
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(Interrupted::Panicked("the worker stopped".to_string()))
+        }
+    }
+}
+
+// Solve the selected parts of a day for an input. A panic or timeout in one
+// part is recorded for that part, one while parsing is recorded for every part.
+pub fn solve_day(
+    solution: &'static dyn DynSolution,
+    input: &str,
+    options: &RunOptions,
+    rejected: &Rejected,
+) -> DayReport {
+    let day = solution.day();
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| part.is_selected_by(options.part));
+    let start = Instant::now();
+    let input = input.to_string();
+    let parsed = match run_isolated(options.timeout, move || solution.parse(&input)) {
+        Ok(parsed) => Arc::new(parsed),
+        Err(interrupted) => {
+            return DayReport {
+                day,
+                parse: None,
+                error: None,
+                records: parts
+                    .map(|part| Record {
+                        day,
+                        part,
+                        answer: None,
+                        duration: None,
+                        status: interrupted.status(),
+                        error: Some(interrupted.describe(" while parsing")),
+                    })
+                    .collect(),
+            }
+        }
+    };
+    let parse = start.elapsed();
+
+    let solve_part = |part| {
+        if let PartStatus::Skipped(reason) = solution.status(part) {
+            return Record {
+                day,
+                part,
+                answer: None,
+                duration: None,
+                status: Status::Skipped,
+                error: Some(reason.to_string()),
+            };
+        }
+        let start = Instant::now();
+        let parsed = Arc::clone(&parsed);
+        let answer = run_isolated(options.timeout, move || solution.solve(part, &parsed));
+        let duration = start.elapsed();
+        match answer.map(|answer| answer.value()) {
+            Ok(None) => Record {
+                day,
+                part,
+                answer: None,
+                duration: Some(duration),
+                status: Status::Unsolved,
+                error: None,
+            },
+            Ok(Some(answer)) => {
+                let (status, error) = match rejected.check(day, part, &answer) {
+                    Some(warning) => (Status::Warning, Some(warning.to_string())),
+                    None => (Status::Ok, None),
+                };
+                Record {
+                    day,
+                    part,
+                    answer: Some(answer),
+                    duration: Some(duration),
+                    status,
+                    error,
+                }
+            }
+            Err(interrupted) => Record {
+                day,
+                part,
+                answer: None,
+                duration: Some(duration),
+                status: interrupted.status(),
+                error: Some(interrupted.describe("")),
+            },
+        }
+    };
+    let records = if options.parallel_parts {
+        let solve_part = &solve_part;
+        thread::scope(|scope| {
+            let workers = parts
+                .map(|part| {
+                    thread::Builder::new()
+                        .stack_size(WORKER_STACK_SIZE)
+                        .spawn_scoped(scope, move || solve_part(part))
+                        .expect("Couldn't start a worker thread")
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap())
+                .collect()
+        })
+    } else {
+        parts.map(solve_part).collect()
+    };
+
+    DayReport {
+        day,
+        parse: Some(parse),
+        error: None,
+        records,
+    }
+}
+
+// Find the solutions for the given days (all registered days if none are given)
+pub fn select_solutions(days: &[u8]) -> Vec<&'static dyn DynSolution> {
+    if days.is_empty() {
+        return solutions();
+    }
+
+    let mut selected = Vec::new();
+    for &day in days {
+        match find_solution(day) {
+            Some(solution) => selected.push(solution),
+            None => eprintln!("No solution registered for day {day}"),
+        }
+    }
+    selected
+}
+
+// Load the rejected answers that go with an input, warning about (but
+// otherwise ignoring) a broken file
+pub fn load_rejected(source: &InputSource) -> Rejected {
+    let Some(dir) = source.dir() else {
+        return Rejected::default();
+    };
+    Rejected::load(&dir.join(REJECTED_FILE)).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        Rejected::default()
+    })
+}
+
+// Run the days on a pool of `jobs` threads, handing the reports to
+// `on_report` in the order of `solutions` as soon as all earlier days are done
+fn run_in_pool(
+    solutions: Vec<&'static dyn DynSolution>,
+    options: &RunOptions,
+    rejected: Arc<Rejected>,
+    jobs: usize,
+    mut on_report: impl FnMut(DayReport),
+) {
+    let (sender, receiver) = mpsc::channel();
+    let pool = ThreadPool::new(jobs, WORKER_STACK_SIZE);
+    for (ix, solution) in solutions.into_iter().enumerate() {
+        let (sender, options, rejected) = (sender.clone(), options.clone(), Arc::clone(&rejected));
+        pool.execute(move || {
+            let _ = sender.send((ix, run(solution, &options, &rejected)));
+        });
+    }
+    drop(sender);
+
+    let mut pending = BTreeMap::new();
+    let mut next = 0;
+    for (ix, report) in receiver {
+        pending.insert(ix, report);
+        while let Some(report) = pending.remove(&next) {
+            on_report(report);
+            next += 1;
+        }
+    }
+}
+
+// Run the selected days. Days whose input can't be read or whose solver
+// panics or times out are reported and the remaining days still run. Text is
+// printed in day order as each day finishes, JSON and CSV once all days are
+// done. Solvers that timed out are left running until the process exits.
+pub fn run_days(days: &[u8], options: &RunOptions) {
+    let rejected = Arc::new(load_rejected(&options.input));
+    let solutions = select_solutions(days);
+    let day_count = solutions.len();
+    let mut records = Vec::new();
+    let mut missing_days = Vec::<u8>::new();
+    let on_report = |report: DayReport| {
+        if report.error.is_some() {
+            missing_days.push(report.day);
+        }
+        if options.format == Format::Text {
+            print!("{}", report.to_text(options.time));
+        }
+        records.extend(report.records);
+    };
+
+    let start = Instant::now();
+    match options.jobs {
+        Some(jobs) => run_in_pool(solutions, options, rejected, jobs, on_report),
+        None => solutions
+            .into_iter()
+            .map(|solution| run(solution, options, &rejected))
+            .for_each(on_report),
+    }
+    let elapsed = start.elapsed();
+
+    match options.format {
+        Format::Json => println!("{}", to_json(&records)),
+        Format::Csv => print!("{}", to_csv(&records)),
+        Format::Text => {
+            let days_with = |status| {
+                let mut days = records
+                    .iter()
+                    .filter(|record| record.status == status)
+                    .map(|record| record.day)
+                    .collect::<Vec<u8>>();
+                days.dedup();
+                days
+            };
+            let (failed_days, timed_out_days) =
+                (days_with(Status::Failed), days_with(Status::Timeout));
+            if !missing_days.is_empty() || !failed_days.is_empty() || !timed_out_days.is_empty() {
+                println!();
+            }
+            if !missing_days.is_empty() {
+                println!(
+                    "Couldn't read the input for day(s): {}",
+                    join(&missing_days)
+                );
+            }
+            if !failed_days.is_empty() {
+                println!("FAILED day(s): {}", join(&failed_days));
+            }
+            if !timed_out_days.is_empty() {
+                println!("TIMEOUT day(s): {}", join(&timed_out_days));
+            }
+            if options.time || options.jobs.is_some() {
+                println!();
+                println!("Ran {day_count} day(s) in {}", format_duration(elapsed));
+            }
+        }
+    }
+}
+
+fn join(days: &[u8]) -> String {
+    days.iter()
+        .map(|day| day.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::answer::Answer;
+    use crate::aoc::day10::Day10;
+    use crate::aoc::solution::Solution;
+
+    struct Panicky;
+
+    impl Solution for Panicky {
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Panicky";
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Self::Input {
+            input
+                .split(',')
+                .map(|n| n.parse::<u32>().expect("Oops!"))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            panic!("No starting point found in {} numbers!", input.len());
+        }
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("Oops!") }),
+            Err("Oops!".to_string())
+        );
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("Unknown tile char {}", 'x') }),
+            Err("Unknown tile char x".to_string())
+        );
+
+        // Only the thread that catches a panic keeps quiet about it
+        assert_eq!(
+            catch_panic(|| catch_panic(|| -> u8 { panic!("Inner") })),
+            Ok(Err("Inner".to_string()))
+        );
+        assert!(!CATCHING_PANICS.with(Cell::get));
+        let other = thread::spawn(|| CATCHING_PANICS.with(Cell::get));
+        assert_eq!(catch_panic(|| other.join().unwrap()), Ok(false));
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let timeout = Some(Duration::from_millis(50));
+        assert_eq!(run_isolated(None, || 1), Ok(1));
+        assert_eq!(run_isolated(timeout, || 1), Ok(1));
+        assert_eq!(
+            run_isolated(timeout, || -> u8 { panic!("Oops!") }),
+            Err(Interrupted::Panicked("Oops!".to_string()))
+        );
+
+        let start = Instant::now();
+        assert_eq!(
+            run_isolated(timeout, || thread::sleep(Duration::from_secs(5))),
+            Err(Interrupted::TimedOut(Duration::from_millis(50)))
+        );
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    struct Endless;
+
+    impl Solution for Endless {
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Endless";
+
+        type Input = String;
+
+        fn parse(input: &str) -> Self::Input {
+            (*input).into()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.as_str().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            // Like walking a network to a node that can't be reached
+            loop {
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_day_times_out() {
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let report = solve_day(&Endless, "AAA", &options, &Rejected::default());
+        assert_eq!(report.records[0].status, Status::Ok);
+        assert_eq!(report.records[0].answer.as_deref(), Some("AAA"));
+        assert_eq!(report.records[1].status, Status::Timeout);
+        assert_eq!(report.records[1].answer, None);
+        assert_eq!(
+            report.records[1].error.as_deref(),
+            Some("timed out after 50.00ms")
+        );
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 24;
+        const TITLE: &'static str = "Slow";
+
+        type Input = u64;
+
+        fn parse(input: &str) -> Self::Input {
+            input.parse().unwrap()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            thread::sleep(Duration::from_millis(*input));
+            (*input).into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            thread::sleep(Duration::from_millis(*input));
+            (input * 2).into()
+        }
+    }
+
+    #[test]
+    fn test_solve_day_parallel_parts() {
+        let options = RunOptions {
+            parallel_parts: true,
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let report = solve_day(&Slow, "100", &options, &Rejected::default());
+        assert!(start.elapsed() < Duration::from_millis(190));
+        let answers = report
+            .records
+            .iter()
+            .map(|record| (record.part, record.answer.as_deref().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(answers, vec![(Part::One, "100"), (Part::Two, "200")]);
+    }
+
+    #[test]
+    fn test_solve_day_unsolved() {
+        let options = RunOptions {
+            part: Some(Part::Two),
+            ..Default::default()
+        };
+        let report = solve_day(&Day10, "S", &options, &Rejected::default());
+        assert_eq!(report.records.len(), 1);
+        assert_eq!(report.records[0].status, Status::Unsolved);
+        assert_eq!(report.records[0].answer, None);
+        assert_eq!(report.records[0].error, None);
+    }
+
+    struct Skipping;
+
+    impl Solution for Skipping {
+        const DAY: u8 = 23;
+        const TITLE: &'static str = "Skipping";
+        const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Skipped("never ends")];
+
+        type Input = String;
+
+        fn parse(input: &str) -> Self::Input {
+            (*input).into()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            panic!("Skipped parts aren't run");
+        }
+    }
+
+    #[test]
+    fn test_solve_day_skips_parts() {
+        let report = solve_day(
+            &Skipping,
+            "abc",
+            &RunOptions::default(),
+            &Rejected::default(),
+        );
+        assert_eq!(report.records[0].status, Status::Ok);
+        assert_eq!(report.records[0].answer.as_deref(), Some("3"));
+        assert_eq!(report.records[1].status, Status::Skipped);
+        assert_eq!(report.records[1].answer, None);
+        assert_eq!(report.records[1].duration, None);
+        assert_eq!(report.records[1].error.as_deref(), Some("never ends"));
+    }
+
+    #[test]
+    fn test_solve_day_isolates_panics() {
+        let options = RunOptions::default();
+        let report = solve_day(&Panicky, "1,2,3", &options, &Rejected::default());
+        assert_eq!(report.records[0].status, Status::Ok);
+        assert_eq!(report.records[0].answer.as_deref(), Some("6"));
+        assert_eq!(report.records[1].status, Status::Failed);
+        assert_eq!(report.records[1].answer, None);
+        assert_eq!(
+            report.records[1].error.as_deref(),
+            Some("panicked: No starting point found in 3 numbers!")
+        );
+
+        let report = solve_day(&Panicky, "1,x", &options, &Rejected::default());
+        assert_eq!(report.parse, None);
+        assert_eq!(report.records.len(), 2);
+        assert!(report.records.iter().all(|record| {
+            record.status == Status::Failed
+                && record
+                    .error
+                    .as_ref()
+                    .is_some_and(|err| err.starts_with("panicked while parsing: Oops!"))
+        }));
+    }
+}
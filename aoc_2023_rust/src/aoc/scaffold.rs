@@ -0,0 +1,245 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Unregistrable(PathBuf, String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Unregistrable(path, reason) => {
+                write!(
+                    f,
+                    "Couldn't register the new day in {}: {reason}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(path, err) => write!(f, "Couldn't access {}: {err}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+lazy_static! {
+    static ref MOD_PATTERN: Regex = Regex::new(r"^pub mod day(\d+);$").unwrap();
+    static ref ENTRY_PATTERN: Regex = Regex::new(r"^(\s*)&day(\d+)::Day\d+,$").unwrap();
+}
+
+// The directory holding the day modules of this crate
+pub fn source_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("aoc")
+}
+
+// A new day module with the same layout and example tests as the solved days
+pub fn template_for(day: u8, title: &str) -> String {
+    format!(
+        r#"use crate::aoc::answer::Answer;
+use crate::aoc::solution::{{PartStatus, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title:?};
+    const STATUS: [PartStatus; 2] = [PartStatus::Unsolved, PartStatus::Unsolved];
+
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {{
+        input.trim().to_string()
+    }}
+
+    fn part1(input: &Self::Input) -> Answer {{
+        solve_part1(input).into()
+    }}
+
+    fn part2(input: &Self::Input) -> Answer {{
+        solve_part2(input).into()
+    }}
+}}
+
+fn solve_part1(_input: &String) -> Answer {{
+    Answer::Unsolved
+}}
+
+fn solve_part2(_input: &String) -> Answer {{
+    Answer::Unsolved
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    const INPUT: [&str; 1] = [""];
+
+    fn get_input(ix: usize) -> String {{
+        String::from(INPUT[ix].trim())
+    }}
+
+    #[test]
+    #[ignore]
+    fn test_full_part1() {{
+        assert_eq!(solve_part1(&get_input(0)), Answer::Unsolved);
+    }}
+
+    #[test]
+    #[ignore]
+    fn test_full_part2() {{
+        assert_eq!(solve_part2(&get_input(0)), Answer::Unsolved);
+    }}
+}}
+"#
+    )
+}
+
+// Insert `line` after the last of `lines` whose day (as matched by `pattern`)
+// comes before `day`, or before the first of them if there is none.
+fn insert_in_day_order(
+    lines: &mut Vec<String>,
+    pattern: &Regex,
+    day_group: usize,
+    day: u8,
+    line: impl Fn(&str) -> String,
+) -> Result<(), String> {
+    let mut days = lines.iter().enumerate().filter_map(|(ix, l)| {
+        let caps = pattern.captures(l)?;
+        let indent = caps.get(1).map_or("", |m| m.as_str()).to_string();
+        Some((ix, caps[day_group].parse::<u8>().ok()?, indent))
+    });
+    let Some(first) = days.next() else {
+        return Err(format!("no line like {:?} found", pattern.as_str()));
+    };
+
+    let mut position = (first.0, first.2.clone());
+    for (ix, other_day, indent) in std::iter::once(first).chain(days) {
+        if other_day == day {
+            return Err(format!("day {day} is already registered"));
+        }
+        if other_day < day {
+            position = (ix + 1, indent);
+        }
+    }
+    lines.insert(position.0, line(&position.1));
+    Ok(())
+}
+
+// Add `pub mod dayN;` and the registry entry for a day to the text of mod.rs
+pub fn register_in(mod_rs: &str, day: u8) -> Result<String, String> {
+    let mut lines = mod_rs.lines().map(String::from).collect::<Vec<String>>();
+    insert_in_day_order(&mut lines, &MOD_PATTERN, 1, day, |_| {
+        format!("pub mod day{day};")
+    })?;
+    insert_in_day_order(&mut lines, &ENTRY_PATTERN, 2, day, |indent| {
+        format!("{indent}&day{day}::Day{day},")
+    })?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Create the module for a new day in `source_dir` and register it in mod.rs.
+// Nothing is written if the day already exists or can't be registered.
+pub fn new_day(source_dir: &Path, day: u8, title: &str) -> Result<PathBuf, ScaffoldError> {
+    let path = source_dir.join(format!("day{day}.rs"));
+    if path.exists() {
+        return Err(ScaffoldError::Exists(path));
+    }
+    let mod_path = source_dir.join("mod.rs");
+    let mod_rs =
+        fs::read_to_string(&mod_path).map_err(|err| ScaffoldError::Io(mod_path.clone(), err))?;
+    let mod_rs = register_in(&mod_rs, day)
+        .map_err(|reason| ScaffoldError::Unregistrable(mod_path.clone(), reason))?;
+
+    fs::write(&path, template_for(day, title))
+        .map_err(|err| ScaffoldError::Io(path.clone(), err))?;
+    fs::write(&mod_path, mod_rs).map_err(|err| ScaffoldError::Io(mod_path, err))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    const MOD_RS: &str = "\
+pub mod day1;
+pub mod day3;
+pub mod answers;
+
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    vec![
+        &day1::Day1,
+        &day3::Day3,
+    ]
+}
+";
+
+    #[test]
+    fn test_register_in() {
+        let registered = register_in(MOD_RS, 2).unwrap();
+        assert!(registered.starts_with("pub mod day1;\npub mod day2;\npub mod day3;\n"));
+        assert!(registered
+            .contains("        &day1::Day1,\n        &day2::Day2,\n        &day3::Day3,\n"));
+
+        let registered = register_in(MOD_RS, 19).unwrap();
+        assert!(registered.contains("pub mod day3;\npub mod day19;\npub mod answers;\n"));
+        assert!(registered.contains("        &day3::Day3,\n        &day19::Day19,\n    ]"));
+
+        assert!(register_in(MOD_RS, 3).is_err());
+        assert!(register_in("pub mod day1;\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_template_for() {
+        let template = template_for(19, "Aplenty");
+        assert!(template.contains("pub struct Day19;"));
+        assert!(template.contains("const DAY: u8 = 19;"));
+        assert!(template.contains("const TITLE: &'static str = \"Aplenty\";"));
+        assert!(template.contains("[PartStatus::Unsolved, PartStatus::Unsolved];"));
+        assert!(template.contains("fn test_full_part1()"));
+        assert!(template.contains("fn test_full_part2()"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = env::temp_dir().join(format!("aoc_2023_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mod.rs"), MOD_RS).unwrap();
+
+        let path = new_day(&dir, 2, "Cube Conundrum").unwrap();
+        assert_eq!(path, dir.join("day2.rs"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            template_for(2, "Cube Conundrum")
+        );
+        assert!(fs::read_to_string(dir.join("mod.rs"))
+            .unwrap()
+            .contains("&day2::Day2,"));
+
+        // Existing days are never overwritten
+        fs::write(&path, "// solved").unwrap();
+        assert!(matches!(
+            new_day(&dir, 2, ""),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "// solved");
+        // A day that's registered but has no file isn't created either
+        assert!(matches!(
+            new_day(&dir, 3, ""),
+            Err(ScaffoldError::Unregistrable(_, _))
+        ));
+        assert!(!dir.join("day3.rs").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,338 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// A cheapest way to a goal: the states from a start to the goal, both included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+// The states seen so far, numbered in the order they were found, with the
+// best cost to each and the state it was reached from
+struct Visited<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    best: Vec<(C, Option<usize>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            index: HashMap::new(),
+            best: Vec::new(),
+        }
+    }
+
+    fn get(&self, state: &S) -> Option<usize> {
+        self.index.get(state).copied()
+    }
+
+    fn insert(&mut self, state: S, cost: C, prev: Option<usize>) -> usize {
+        let node = self.states.len();
+        self.index.insert(state.clone(), node);
+        self.states.push(state);
+        self.best.push((cost, prev));
+        node
+    }
+
+    fn path_to(&self, node: usize) -> Path<S, C> {
+        let mut states = vec![self.states[node].clone()];
+        let mut current = self.best[node].1;
+        while let Some(prev) = current {
+            states.push(self.states[prev].clone());
+            current = self.best[prev].1;
+        }
+        states.reverse();
+        Path {
+            cost: self.best[node].0,
+            states,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Entry<C> {
+    priority: C,
+    cost: C,
+    node: usize,
+}
+
+// Manually implement Ord so we get a min-heap instead of a max-heap
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The cheapest path from any of `starts` to a state where `is_goal` holds.
+// `neighbors` gives the states reachable from a state and what each step
+// costs. Adapted from https://rosettacode.org/wiki/Dijkstra%27s_algorithm#Rust
+pub fn dijkstra<S, C, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbors: N,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+// Like `dijkstra`, but searching towards the goal first. `heuristic` has to
+// estimate the cost from a state to the goal without ever overestimating it.
+pub fn astar<S, C, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if visited.get(&start).is_none() {
+            let priority = heuristic(&start);
+            let node = visited.insert(start, C::default(), None);
+            heap.push(Entry {
+                priority,
+                cost: C::default(),
+                node,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if cost > visited.best[node].0 {
+            continue;
+        }
+        if is_goal(&visited.states[node]) {
+            return Some(visited.path_to(node));
+        }
+
+        for (next, step) in neighbors(&visited.states[node]) {
+            let next_cost = cost + step;
+            let next_node = match visited.get(&next) {
+                Some(seen) if visited.best[seen].0 <= next_cost => continue,
+                Some(seen) => {
+                    visited.best[seen] = (next_cost, Some(node));
+                    seen
+                }
+                None => visited.insert(next, next_cost, Some(node)),
+            };
+            heap.push(Entry {
+                priority: next_cost + heuristic(&visited.states[next_node]),
+                cost: next_cost,
+                node: next_node,
+            });
+        }
+    }
+    None
+}
+
+// The shortest path, counted in steps, from any of `starts` to a state where
+// `is_goal` holds
+pub fn bfs<S, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if visited.get(&start).is_none() {
+            queue.push_back(visited.insert(start, 0, None));
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&visited.states[node]) {
+            return Some(visited.path_to(node));
+        }
+        let steps = visited.best[node].0 + 1;
+        for next in neighbors(&visited.states[node]) {
+            if visited.get(&next).is_none() {
+                queue.push_back(visited.insert(next, steps, Some(node)));
+            }
+        }
+    }
+    None
+}
+
+// The number of steps to every state that can be reached from any of `starts`
+pub fn bfs_distances<S, N, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbors: N,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let steps = distances[&state] + 1;
+        for next in neighbors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::grid::{Grid, Pos};
+
+    // Walls are '#', every other cell costs its digit or 1 to enter. The
+    // path along the top has fewer steps, the one along the bottom costs less.
+    fn maze() -> Grid<char> {
+        "\
+S.9....
+.#####E
+......."
+            .parse()
+            .unwrap()
+    }
+
+    fn open_neighbors(maze: &Grid<char>, pos: &Pos) -> Vec<Pos> {
+        maze.neighbors4(*pos)
+            .filter(|&next| maze[next] != '#')
+            .collect()
+    }
+
+    fn weighted_neighbors(maze: &Grid<char>, pos: &Pos) -> Vec<(Pos, u32)> {
+        open_neighbors(maze, pos)
+            .into_iter()
+            .map(|next| (next, maze[next].to_digit(10).unwrap_or(1)))
+            .collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let maze = maze();
+        let end = maze.find(|&ch| ch == 'E').unwrap();
+        let path = dijkstra(
+            [(0, 0)],
+            |pos| weighted_neighbors(&maze, pos),
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 9);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&end));
+        assert_eq!(path.states.len(), 10);
+        assert!(!path.states.contains(&(0, 2)));
+
+        // Starting from either end of the bottom row
+        let path = dijkstra(
+            [(2, 0), (2, 6)],
+            |pos| weighted_neighbors(&maze, pos),
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(path.states, vec![(2, 6), (1, 6)]);
+
+        assert_eq!(
+            dijkstra(
+                [(0, 0)],
+                |pos| weighted_neighbors(&maze, pos),
+                |&pos| pos == (1, 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = maze();
+        let end = maze.find(|&ch| ch == 'E').unwrap();
+        let distance = |&(r, c): &Pos| (r.abs_diff(end.0) + c.abs_diff(end.1)) as u32;
+        let path = astar(
+            [(0, 0)],
+            |pos| weighted_neighbors(&maze, pos),
+            distance,
+            |&pos| pos == end,
+        )
+        .unwrap();
+        let expected = dijkstra(
+            [(0, 0)],
+            |pos| weighted_neighbors(&maze, pos),
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let end = maze.find(|&ch| ch == 'E').unwrap();
+        let path = bfs(
+            [(0, 0)],
+            |pos| open_neighbors(&maze, pos),
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states.len(), 8);
+        assert!(path.states.contains(&(0, 2)));
+        assert_eq!(
+            bfs(
+                [(0, 0)],
+                |pos| open_neighbors(&maze, pos),
+                |&pos| pos == (1, 1)
+            ),
+            None
+        );
+
+        let distances = bfs_distances([(0, 0)], |pos| open_neighbors(&maze, pos));
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(0, 2)], 2);
+        assert_eq!(distances[&(2, 6)], 8);
+        assert_eq!(distances[&end], 7);
+        assert_eq!(distances.get(&(1, 1)), None);
+        assert_eq!(distances.len(), 16);
+
+        let distances = bfs_distances([(0, 0), end], |pos| open_neighbors(&maze, pos));
+        assert_eq!(distances[&(2, 6)], 1);
+        assert_eq!(distances[&(0, 2)], 2);
+    }
+}
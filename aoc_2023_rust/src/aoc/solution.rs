@@ -0,0 +1,125 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::Part;
+use std::any::Any;
+use std::fmt;
+
+// How far along the solution to one part of a day is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    // Solved, but the example tests are ignored or commented out
+    IgnoredTests,
+    // Runs, but gives wrong answers for some examples
+    Partial,
+    // Not run at all, for the given reason
+    Skipped(&'static str),
+    // Not started yet
+    Unsolved,
+}
+
+impl fmt::Display for PartStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartStatus::Solved => write!(f, "solved"),
+            PartStatus::IgnoredTests => write!(f, "solved, with ignored tests"),
+            PartStatus::Partial => write!(f, "partially solved"),
+            PartStatus::Skipped(reason) => write!(f, "skipped, {reason}"),
+            PartStatus::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+// A day's puzzle: how to parse its input and how to solve both parts.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    // The status of part 1 and part 2
+    const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Solved];
+
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+// Object safe view of a `Solution` so that every day can live in one registry.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn status(&self, part: Part) -> PartStatus;
+    fn parse(&self, input: &str) -> Parsed;
+    fn solve(&self, part: Part, parsed: &Parsed) -> Answer;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn status(&self, part: Part) -> PartStatus {
+        match part {
+            Part::One => S::STATUS[0],
+            Part::Two => S::STATUS[1],
+        }
+    }
+
+    fn parse(&self, input: &str) -> Parsed {
+        Box::new(S::parse(input))
+    }
+
+    fn solve(&self, part: Part, parsed: &Parsed) -> Answer {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Input for day {} was parsed by another day", S::DAY));
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 25;
+        const TITLE: &'static str = "Example";
+        const STATUS: [PartStatus; 2] = [PartStatus::Solved, PartStatus::Unsolved];
+
+        type Input = Vec<i32>;
+
+        fn parse(input: &str) -> Self::Input {
+            input.split(',').map(|n| n.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i32>().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Example;
+        assert_eq!(solution.day(), 25);
+        assert_eq!(solution.title(), "Example");
+        assert_eq!(solution.status(Part::One), PartStatus::Solved);
+        assert_eq!(solution.status(Part::Two), PartStatus::Unsolved);
+        let parsed = solution.parse("2,3,4");
+        assert_eq!(solution.solve(Part::One, &parsed), "9");
+        assert_eq!(solution.solve(Part::Two, &parsed), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::aoc::answers::{Answers, AnswersError, ANSWERS_FILE};
+use crate::aoc::fetch::{session_from_env, Client, FetchError, DEFAULT_DELAY};
+use crate::aoc::input::{input_dir, read_input_from_dir};
+use crate::aoc::rejected::{Hint, Rejected, Rejection, REJECTED_FILE};
+use crate::aoc::runner::catch_panic;
+use crate::aoc::{find_solution, Part};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Option<Hint>),
+    AlreadySolved,
+    RateLimited(Option<Duration>),
+    Unknown(String),
+}
+
+lazy_static! {
+    static ref ARTICLE_PATTERN: Regex = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    static ref TAG_PATTERN: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref WAIT_PATTERN: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+// Sort the page the server sends back after a submission into an outcome
+pub fn classify(page: &str) -> Outcome {
+    let text = match ARTICLE_PATTERN.captures(page) {
+        Some(article) => TAG_PATTERN.replace_all(&article[1], "").to_string(),
+        None => TAG_PATTERN.replace_all(page, "").to_string(),
+    };
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Outcome::Wrong(hint)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if text.contains("You gave an answer too recently") {
+        let wait = WAIT_PATTERN.captures(&text).map(|caps| {
+            let minutes = caps
+                .get(1)
+                .map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+            let seconds = caps[2].parse::<u64>().unwrap();
+            Duration::from_secs(minutes * 60 + seconds)
+        });
+        Outcome::RateLimited(wait)
+    } else {
+        Outcome::Unknown(text.trim().to_string())
+    }
+}
+
+pub fn submit_answer(
+    client: &mut Client,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, FetchError> {
+    let level = part.to_string();
+    let page = client.post_form(
+        &format!("{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    Ok(classify(&page))
+}
+
+// Record the outcome in the answers or the rejected answers next to the inputs
+fn record(
+    input_dir: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+    outcome: &Outcome,
+) -> Result<(), AnswersError> {
+    match outcome {
+        Outcome::Correct => {
+            let path = input_dir.join(ANSWERS_FILE);
+            let mut answers = Answers::load(&path)?;
+            answers.set(day, part, answer.to_string());
+            answers.save(&path)?;
+            println!("Recorded the answer in {}", path.display());
+        }
+        Outcome::Wrong(hint) => {
+            let path = input_dir.join(REJECTED_FILE);
+            let mut rejected = Rejected::load(&path)?;
+            let rejection = Rejection {
+                answer: answer.to_string(),
+                hint: *hint,
+            };
+            rejected.add(day, part, rejection);
+            rejected.save(&path)?;
+            println!("Recorded the rejected answer in {}", path.display());
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+// Solve one part of a day and submit the answer. Returns true if it was correct.
+pub fn submit(day: u8, part: Part, base_url: &str) -> bool {
+    let Some(solution) = find_solution(day) else {
+        println!("No solution registered for day {day}");
+        return false;
+    };
+    let (session, input_dir) = match (session_from_env(), input_dir()) {
+        (Ok(session), Ok(input_dir)) => (session, input_dir),
+        (Err(err), _) => {
+            println!("{err}");
+            return false;
+        }
+        (_, Err(err)) => {
+            println!("{err}");
+            return false;
+        }
+    };
+    let input = match read_input_from_dir(&input_dir, day) {
+        Ok(input) => input,
+        Err(err) => {
+            println!("{err}");
+            return false;
+        }
+    };
+
+    // A panicking solver is reported like in a run, instead of taking the
+    // submission down with it
+    let answer = match catch_panic(|| solution.solve(part, &solution.parse(&input))) {
+        Ok(answer) => answer,
+        Err(message) => {
+            println!("Day {day} Part{part}: FAILED ({message})");
+            return false;
+        }
+    };
+    let Some(answer) = answer.value() else {
+        println!("Day {day} Part{part} isn't solved yet");
+        return false;
+    };
+    println!("Day {day} Part{part}: {answer}");
+    if answer.trim().is_empty() {
+        println!("Not submitting an empty answer");
+        return false;
+    }
+    // Without the rejected answers, one could be submitted again
+    let rejected = match Rejected::load(&input_dir.join(REJECTED_FILE)) {
+        Ok(rejected) => rejected,
+        Err(err) => {
+            println!("{err}");
+            println!("Not submitting without the rejected answers");
+            return false;
+        }
+    };
+    if let Some(warning) = rejected.check(day, part, &answer) {
+        println!("Not submitting, the answer {warning}");
+        return false;
+    }
+
+    let mut client = Client::new(base_url, session, DEFAULT_DELAY);
+    let outcome = match submit_answer(&mut client, day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            println!("{err}");
+            return false;
+        }
+    };
+    match &outcome {
+        Outcome::Correct => println!("That's the right answer!"),
+        Outcome::Wrong(Some(hint)) => println!("That's not the right answer, it's {hint}"),
+        Outcome::Wrong(None) => println!("That's not the right answer"),
+        Outcome::AlreadySolved => println!("This part was already solved"),
+        Outcome::RateLimited(Some(wait)) => {
+            println!("Answered too recently, wait {}s", wait.as_secs())
+        }
+        Outcome::RateLimited(None) => println!("Answered too recently"),
+        Outcome::Unknown(text) => println!("Unexpected response:\n{text}"),
+    }
+    if let Err(err) = record(&input_dir, day, part, &answer, &outcome) {
+        println!("{err}");
+    }
+
+    outcome == Outcome::Correct
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aoc::fetch::session_cookie_from;
+    use crate::aoc::fetch::test::serve;
+    use std::env;
+    use std::fs;
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_classify() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(classify(&correct), Outcome::Correct);
+
+        let too_high =
+            page("That's not the right answer; your answer is too high.  If you're stuck, ...");
+        assert_eq!(classify(&too_high), Outcome::Wrong(Some(Hint::TooHigh)));
+
+        let too_low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(classify(&too_low), Outcome::Wrong(Some(Hint::TooLow)));
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(classify(&wrong), Outcome::Wrong(None));
+
+        let solved =
+            page("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(classify(&solved), Outcome::AlreadySolved);
+
+        let rate_limited = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 21s left to wait.");
+        assert_eq!(
+            classify(&rate_limited),
+            Outcome::RateLimited(Some(Duration::from_secs(261)))
+        );
+        let rate_limited = page("You gave an answer too recently.  You have 35s left to wait.");
+        assert_eq!(
+            classify(&rate_limited),
+            Outcome::RateLimited(Some(Duration::from_secs(35)))
+        );
+
+        assert_eq!(classify("<p>Hmm</p>"), Outcome::Unknown("Hmm".to_string()));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, requests) =
+            serve(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let mut client = Client::new(&base_url, session_cookie_from("abc"), Duration::ZERO);
+
+        let outcome = submit_answer(&mut client, 13, Part::Two, "21700").unwrap();
+        assert_eq!(outcome, Outcome::Wrong(Some(Hint::TooLow)));
+        let (request_line, cookie, body) = requests.recv().unwrap();
+        assert_eq!(request_line, "POST /2023/day/13/answer HTTP/1.1");
+        assert_eq!(cookie, "session=abc");
+        assert_eq!(body, "level=2&answer=21700");
+
+        let outcome = submit_answer(&mut client, 13, Part::Two, "30000").unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = env::temp_dir().join(format!("aoc_2023_submit_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        record(
+            &dir,
+            13,
+            Part::Two,
+            "21700",
+            &Outcome::Wrong(Some(Hint::TooLow)),
+        )
+        .unwrap();
+        record(&dir, 13, Part::Two, "30000", &Outcome::Correct).unwrap();
+        record(&dir, 13, Part::One, "1", &Outcome::AlreadySolved).unwrap();
+
+        let answers = Answers::load(&dir.join(ANSWERS_FILE)).unwrap();
+        assert_eq!(answers.get(13, Part::Two), Some("30000"));
+        assert_eq!(answers.get(13, Part::One), None);
+        let rejected = Rejected::load(&dir.join(REJECTED_FILE)).unwrap();
+        assert_eq!(
+            rejected.check(13, Part::Two, "21700"),
+            Some(crate::aoc::rejected::Warning::Rejected(Some(Hint::TooLow)))
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use crate::aoc::answer::Answer;
+use crate::aoc::answers::{Answers, ANSWERS_FILE};
+use crate::aoc::input::{input_dir_for, read_input_from_dir};
+use crate::aoc::runner::{run_isolated, select_solutions};
+use crate::aoc::solution::PartStatus;
+use crate::aoc::Part;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Regression { expected: String },
+    Unverified,
+    // Not solved yet, and there's no accepted answer to miss
+    Unsolved,
+}
+
+pub fn check(expected: Option<&str>, actual: &Answer) -> Verdict {
+    match (expected, actual) {
+        (Some(expected), actual) if *actual == expected => Verdict::Correct,
+        (Some(expected), _) => Verdict::Regression {
+            expected: expected.to_string(),
+        },
+        (None, Answer::Unsolved) => Verdict::Unsolved,
+        (None, _) => Verdict::Unverified,
+    }
+}
+
+// Solve the selected days and compare every answer with the accepted answers
+// in `answers.toml` of the inputs of `profile`. Returns false if any answer
+// changed or any solver panicked or timed out.
+pub fn verify_days(
+    days: &[u8],
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    profile: Option<&str>,
+) -> bool {
+    let dir = match input_dir_for(profile) {
+        Ok(dir) => dir,
+        Err(err) => {
+            println!("{err}");
+            return false;
+        }
+    };
+    let answers = match Answers::load(&dir.join(ANSWERS_FILE)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            println!("{err}");
+            return false;
+        }
+    };
+
+    let mut regressions = 0;
+    let mut failed = 0;
+    let mut unverified = 0;
+    println!("{:>3}  {:>4}  {:<10}  Answer", "Day", "Part", "Status");
+    for solution in select_solutions(days) {
+        let day = solution.day();
+        let input = match read_input_from_dir(&dir, day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{day:>3}  {:>4}  {:<10}  {err}", "", "MISSING");
+                unverified += 1;
+                continue;
+            }
+        };
+        let parsed = match run_isolated(timeout, move || solution.parse(&input)) {
+            Ok(parsed) => Arc::new(parsed),
+            Err(interrupted) => {
+                println!(
+                    "{day:>3}  {:>4}  {:<10}  {}",
+                    "",
+                    interrupted.status().to_string().to_uppercase(),
+                    interrupted.describe(" while parsing")
+                );
+                failed += 1;
+                continue;
+            }
+        };
+        for current in [Part::One, Part::Two] {
+            if !current.is_selected_by(part) {
+                continue;
+            }
+            if let PartStatus::Skipped(reason) = solution.status(current) {
+                println!("{day:>3}  {current:>4}  {:<10}  {reason}", "skipped");
+                continue;
+            }
+            let parsed = Arc::clone(&parsed);
+            let answer = match run_isolated(timeout, move || solution.solve(current, &parsed)) {
+                Ok(answer) => answer,
+                Err(interrupted) => {
+                    println!(
+                        "{day:>3}  {current:>4}  {:<10}  {}",
+                        interrupted.status().to_string().to_uppercase(),
+                        interrupted.describe("")
+                    );
+                    failed += 1;
+                    continue;
+                }
+            };
+            let (status, details) = match check(answers.get(day, current), &answer) {
+                Verdict::Correct => ("ok", answer.to_string()),
+                Verdict::Regression { expected } => {
+                    regressions += 1;
+                    ("REGRESSION", format!("{answer} (expected {expected})"))
+                }
+                Verdict::Unverified => {
+                    unverified += 1;
+                    ("unverified", answer.to_string())
+                }
+                Verdict::Unsolved => ("unsolved", String::new()),
+            };
+            println!("{day:>3}  {current:>4}  {status:<10}  {details}");
+        }
+    }
+
+    println!();
+    println!("{regressions} regression(s), {failed} failed, {unverified} unverified");
+    regressions == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("142"), &Answer::Int(142)), Verdict::Correct);
+        assert_eq!(check(Some("abc"), &Answer::from("abc")), Verdict::Correct);
+        assert_eq!(
+            check(Some("142"), &Answer::Int(141)),
+            Verdict::Regression {
+                expected: "142".to_string()
+            }
+        );
+        assert_eq!(
+            check(Some("142"), &Answer::Unsolved),
+            Verdict::Regression {
+                expected: "142".to_string()
+            }
+        );
+        assert_eq!(check(None, &Answer::Int(142)), Verdict::Unverified);
+        assert_eq!(check(None, &Answer::Unsolved), Verdict::Unsolved);
+    }
+}
@@ -0,0 +1,711 @@
+use rust_aoc_2023::aoc::config::Config;
+use rust_aoc_2023::aoc::fetch::{base_url_from_env, DEFAULT_DELAY};
+use rust_aoc_2023::aoc::input::InputSource;
+use rust_aoc_2023::aoc::report::Format;
+use rust_aoc_2023::aoc::runner::RunOptions;
+use rust_aoc_2023::aoc::{parse_day_from, parse_days_from, Part};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: rust_aoc_2023 [DAYS...] [--part 1|2] [--time] [--format json|csv|text] [--timeout SECS|none]
+                     [--jobs N] [--parallel-parts] [--input PATH|-] [--profile NAME]
+       rust_aoc_2023 bench [DAYS...] [--part 1|2] [--iterations N]
+       rust_aoc_2023 verify [DAYS...] [--part 1|2] [--timeout SECS|none] [--profile NAME]
+       rust_aoc_2023 compare [DAYS...] [--part 1|2] [--timeout SECS|none]
+       rust_aoc_2023 fetch [DAYS...] [--base-url URL] [--delay SECONDS]
+       rust_aoc_2023 submit DAY PART [--base-url URL]
+       rust_aoc_2023 new DAY [--title TITLE]
+       rust_aoc_2023 examples PAGE
+       rust_aoc_2023 list
+       rust_aoc_2023 status
+
+Arguments:
+    DAYS              Days to run, e.g. `3`, `1,4,9` or `5-8` (default: all days)
+
+Options (defaults for the days, timeout, format, jobs and fetch options can be set in aoc.toml):
+    --part N          Only run part 1 or part 2 of each day
+    --time            Print how long parsing and each part took
+    --format FORMAT   Print one record per part as json, csv or text (default: text)
+    --timeout SECS    Give up on parsing or a part after this long, or never for `none`
+                      (default: no limit)
+    -j, --jobs N      Run N days at once, printing them in day order
+    --parallel-parts  Solve the two parts of each day at the same time
+    --input PATH      Solve a single day for the input in PATH, or in stdin for `-`
+    --profile NAME    Use the inputs in $AOC_INPUT_DIR/NAME (default: $AOC_PROFILE)
+    -n, --iterations  How many times `bench` repeats each step (default: 10)
+    --base-url URL    Server to talk to (default: $AOC_BASE_URL or adventofcode.com)
+    --delay SECONDS   Minimum time between requests to the server (default: 5)
+    --title TITLE     Puzzle title of a `new` day
+    -h, --help        Print this message
+
+Commands:
+    bench             Time the parse step and each part of the selected days
+    verify            Compare answers with those recorded in $AOC_INPUT_DIR/answers.toml
+    compare           Solve the selected days for every profile and tabulate the answers
+    fetch             Download missing inputs into $AOC_INPUT_DIR using $AOC_SESSION_TOKEN
+    submit            Solve one part of a day, submit the answer and record the result
+    new               Create the module for a new day from a template and register it
+    examples          Copy the examples and their answers from a saved puzzle page into the tests
+    list              List the days with a registered solution
+    status            Show a calendar of solved, partial and skipped parts";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        days: Vec<u8>,
+        options: RunOptions,
+    },
+    Bench {
+        days: Vec<u8>,
+        part: Option<Part>,
+        iterations: usize,
+    },
+    Verify {
+        days: Vec<u8>,
+        part: Option<Part>,
+        timeout: Option<Duration>,
+        profile: Option<String>,
+    },
+    Compare {
+        days: Vec<u8>,
+        part: Option<Part>,
+        timeout: Option<Duration>,
+    },
+    Fetch {
+        days: Vec<u8>,
+        base_url: String,
+        delay: Duration,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+        base_url: String,
+    },
+    New {
+        day: u8,
+        title: String,
+    },
+    Examples {
+        page: PathBuf,
+    },
+    List,
+    Status,
+    Help,
+}
+
+#[derive(Debug, Default)]
+struct Args {
+    positionals: Vec<String>,
+    options: Vec<&'static str>,
+    part: Option<Part>,
+    time: bool,
+    iterations: Option<usize>,
+    base_url: Option<String>,
+    delay: Option<Duration>,
+    title: Option<String>,
+    format: Option<Format>,
+    timeout: Option<Duration>,
+    no_timeout: bool,
+    jobs: Option<usize>,
+    parallel_parts: bool,
+    input: Option<InputSource>,
+    profile: Option<String>,
+    default_days: Vec<u8>,
+}
+
+impl Args {
+    // Make sure that only options the subcommand understands were given
+    fn allow(&self, command: &str, allowed: &[&str]) -> Result<(), String> {
+        match self.options.iter().find(|option| !allowed.contains(option)) {
+            Some(option) => Err(format!("{option} can't be used with `{command}`")),
+            None => Ok(()),
+        }
+    }
+
+    fn days(&self) -> Result<Vec<u8>, String> {
+        if self.positionals.is_empty() {
+            return Ok(self.default_days.clone());
+        }
+        let mut days = Vec::<u8>::new();
+        for positional in self.positionals.iter() {
+            days.extend(parse_days_from(positional)?);
+        }
+        days.sort_unstable();
+        days.dedup();
+        Ok(days)
+    }
+}
+
+// Parse the command line, taking defaults for the flags that weren't given
+// from `config`
+pub fn parse_args<I>(args: I, config: &Config) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    let subcommand = match args.peek().map(String::as_str) {
+        Some(
+            name @ ("list" | "status" | "bench" | "verify" | "compare" | "fetch" | "submit" | "new"
+            | "examples"),
+        ) => {
+            let name = name.to_string();
+            args.next();
+            Some(name)
+        }
+        _ => None,
+    };
+
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value_for = |flag: &str| {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or(format!("Missing value for {flag}"))
+        };
+        let option = match flag {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-p" | "--part" => {
+                parsed.part = Some(value_for(flag)?.parse::<Part>()?);
+                "--part"
+            }
+            "--time" => {
+                parsed.time = true;
+                "--time"
+            }
+            "-n" | "--iterations" => match value_for(flag)?.parse::<usize>() {
+                Ok(n) if n > 0 => {
+                    parsed.iterations = Some(n);
+                    "--iterations"
+                }
+                _ => return Err(format!("Invalid value for {flag}")),
+            },
+            "--base-url" => {
+                parsed.base_url = Some(value_for(flag)?);
+                "--base-url"
+            }
+            "--delay" => match value_for(flag)?.parse::<f64>() {
+                Ok(seconds) if seconds >= 0.0 => {
+                    parsed.delay = Some(Duration::from_secs_f64(seconds));
+                    "--delay"
+                }
+                _ => return Err(format!("Invalid value for {flag}")),
+            },
+            "--format" => {
+                parsed.format = Some(value_for(flag)?.parse::<Format>()?);
+                "--format"
+            }
+            "--timeout" => match value_for(flag)?.as_str() {
+                "none" => {
+                    (parsed.timeout, parsed.no_timeout) = (None, true);
+                    "--timeout"
+                }
+                value => match value.parse::<f64>() {
+                    Ok(seconds) if seconds > 0.0 => {
+                        parsed.timeout = Some(Duration::from_secs_f64(seconds));
+                        parsed.no_timeout = false;
+                        "--timeout"
+                    }
+                    _ => return Err(format!("Invalid value for {flag}")),
+                },
+            },
+            "-j" | "--jobs" => match value_for(flag)?.parse::<usize>() {
+                Ok(n) if n > 0 => {
+                    parsed.jobs = Some(n);
+                    "--jobs"
+                }
+                _ => return Err(format!("Invalid value for {flag}")),
+            },
+            "--parallel-parts" => {
+                parsed.parallel_parts = true;
+                "--parallel-parts"
+            }
+            "--input" => {
+                parsed.input = Some(value_for(flag)?.parse::<InputSource>()?);
+                "--input"
+            }
+            "--profile" => {
+                parsed.profile = Some(value_for(flag)?);
+                "--profile"
+            }
+            "--title" => {
+                parsed.title = Some(value_for(flag)?);
+                "--title"
+            }
+            _ if flag.starts_with('-') => return Err(format!("Unknown option: {flag}")),
+            _ => {
+                parsed.positionals.push(arg);
+                continue;
+            }
+        };
+        parsed.options.push(option);
+    }
+    parsed.default_days = config.days.clone();
+    if !parsed.no_timeout {
+        parsed.timeout = parsed.timeout.or(config.timeout);
+    }
+    parsed.format = parsed.format.or(config.format);
+    parsed.jobs = parsed.jobs.or(config.jobs);
+    parsed.delay = parsed.delay.or(config.delay);
+
+    match subcommand.as_deref() {
+        Some("list") => {
+            parsed.allow("list", &[])?;
+            if !parsed.positionals.is_empty() {
+                return Err("`list` doesn't take any arguments".to_string());
+            }
+            Ok(Command::List)
+        }
+        Some("status") => {
+            parsed.allow("status", &[])?;
+            if !parsed.positionals.is_empty() {
+                return Err("`status` doesn't take any arguments".to_string());
+            }
+            Ok(Command::Status)
+        }
+        Some("bench") => {
+            parsed.allow("bench", &["--part", "--iterations"])?;
+            Ok(Command::Bench {
+                days: parsed.days()?,
+                part: parsed.part,
+                iterations: parsed.iterations.unwrap_or(DEFAULT_ITERATIONS),
+            })
+        }
+        Some("verify") => {
+            parsed.allow("verify", &["--part", "--timeout", "--profile"])?;
+            Ok(Command::Verify {
+                days: parsed.days()?,
+                part: parsed.part,
+                timeout: parsed.timeout,
+                profile: parsed.profile,
+            })
+        }
+        Some("compare") => {
+            parsed.allow("compare", &["--part", "--timeout"])?;
+            Ok(Command::Compare {
+                days: parsed.days()?,
+                part: parsed.part,
+                timeout: parsed.timeout,
+            })
+        }
+        Some("fetch") => {
+            parsed.allow("fetch", &["--base-url", "--delay"])?;
+            Ok(Command::Fetch {
+                days: parsed.days()?,
+                base_url: parsed.base_url.unwrap_or_else(base_url_from_env),
+                delay: parsed.delay.unwrap_or(DEFAULT_DELAY),
+            })
+        }
+        Some("submit") => {
+            parsed.allow("submit", &["--base-url"])?;
+            let [day, part] = &parsed.positionals[..] else {
+                return Err("`submit` takes a day and a part".to_string());
+            };
+            Ok(Command::Submit {
+                day: parse_day_from(day)?,
+                part: part.parse::<Part>()?,
+                base_url: parsed.base_url.unwrap_or_else(base_url_from_env),
+            })
+        }
+        Some("new") => {
+            parsed.allow("new", &["--title"])?;
+            let [day] = &parsed.positionals[..] else {
+                return Err("`new` takes a single day".to_string());
+            };
+            Ok(Command::New {
+                day: parse_day_from(day)?,
+                title: parsed.title.unwrap_or_default(),
+            })
+        }
+        Some("examples") => {
+            parsed.allow("examples", &[])?;
+            let [page] = &parsed.positionals[..] else {
+                return Err("`examples` takes the path of a saved puzzle page".to_string());
+            };
+            Ok(Command::Examples {
+                page: PathBuf::from(page),
+            })
+        }
+        _ => {
+            parsed.allow(
+                "run",
+                &[
+                    "--part",
+                    "--time",
+                    "--format",
+                    "--timeout",
+                    "--jobs",
+                    "--parallel-parts",
+                    "--input",
+                    "--profile",
+                ],
+            )?;
+            let days = parsed.days()?;
+            if parsed.input.is_some() && days.len() != 1 {
+                return Err("`--input` needs a single day to solve".to_string());
+            }
+            let input = match (parsed.input, parsed.profile) {
+                (Some(_), Some(_)) => {
+                    return Err("`--input` and `--profile` can't be used together".to_string())
+                }
+                (Some(input), None) => input,
+                (None, Some(profile)) => InputSource::Profile(profile),
+                (None, None) => InputSource::Dir,
+            };
+            Ok(Command::Run {
+                days,
+                options: RunOptions {
+                    part: parsed.part,
+                    time: parsed.time,
+                    format: parsed.format.unwrap_or_default(),
+                    timeout: parsed.timeout,
+                    jobs: parsed.jobs,
+                    parallel_parts: parsed.parallel_parts,
+                    input,
+                },
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()), &Config::default())
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Command::Run {
+                days: vec![],
+                options: RunOptions::default(),
+            })
+        );
+        assert_eq!(
+            parse(&["7-9", "3", "--part", "2"]),
+            Ok(Command::Run {
+                days: vec![3, 7, 8, 9],
+                options: RunOptions {
+                    part: Some(Part::Two),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse(&["--part=1", "4,4", "--time"]),
+            Ok(Command::Run {
+                days: vec![4],
+                options: RunOptions {
+                    part: Some(Part::One),
+                    time: true,
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse(&["--format", "json"]),
+            Ok(Command::Run {
+                days: vec![],
+                options: RunOptions {
+                    format: Format::Json,
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(parse(&["list"]), Ok(Command::List));
+        assert_eq!(parse(&["--help"]), Ok(Command::Help));
+        assert!(parse(&["list", "3"]).is_err());
+        assert_eq!(parse(&["status"]), Ok(Command::Status));
+        assert!(parse(&["status", "5"]).is_err());
+        assert!(parse(&["status", "--time"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--part"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert_eq!(
+            parse(&["8", "--timeout=10"]),
+            Ok(Command::Run {
+                days: vec![8],
+                options: RunOptions {
+                    timeout: Some(Duration::from_secs(10)),
+                    ..Default::default()
+                },
+            })
+        );
+        assert!(parse(&["bench", "--timeout", "1"]).is_err());
+        assert_eq!(
+            parse(&["-j", "4", "--parallel-parts"]),
+            Ok(Command::Run {
+                days: vec![],
+                options: RunOptions {
+                    jobs: Some(4),
+                    parallel_parts: true,
+                    ..Default::default()
+                },
+            })
+        );
+        assert!(parse(&["--jobs", "0"]).is_err());
+        assert!(parse(&["verify", "--jobs", "2"]).is_err());
+        assert!(parse(&["verify", "--format=csv"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_with_config() {
+        let config = Config {
+            days: vec![1, 2],
+            timeout: Some(Duration::from_secs(30)),
+            format: Some(Format::Csv),
+            jobs: Some(4),
+            delay: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()), &config);
+        assert_eq!(
+            parse(&[]),
+            Ok(Command::Run {
+                days: vec![1, 2],
+                options: RunOptions {
+                    format: Format::Csv,
+                    timeout: Some(Duration::from_secs(30)),
+                    jobs: Some(4),
+                    ..Default::default()
+                },
+            })
+        );
+        // Flags win over the config
+        assert_eq!(
+            parse(&["5", "--format", "text", "--jobs", "1"]),
+            Ok(Command::Run {
+                days: vec![5],
+                options: RunOptions {
+                    timeout: Some(Duration::from_secs(30)),
+                    jobs: Some(1),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse(&["5", "--timeout", "none"]),
+            Ok(Command::Run {
+                days: vec![5],
+                options: RunOptions {
+                    format: Format::Csv,
+                    jobs: Some(4),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "--timeout=none"]),
+            Ok(Command::Verify {
+                days: vec![1, 2],
+                part: None,
+                timeout: None,
+                profile: None,
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "--timeout", "none", "--timeout", "5"]),
+            Ok(Command::Verify {
+                days: vec![1, 2],
+                part: None,
+                timeout: Some(Duration::from_secs(5)),
+                profile: None,
+            })
+        );
+        assert_eq!(
+            parse(&["fetch", "--delay", "0"]),
+            Ok(Command::Fetch {
+                days: vec![1, 2],
+                base_url: base_url_from_env(),
+                delay: Duration::ZERO,
+            })
+        );
+        // Settings a command doesn't use aren't an error, unlike its flags
+        assert!(parse(&["bench"]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_input_args() {
+        assert_eq!(
+            parse(&["10", "--input", "inputs/alice/day10.txt"]),
+            Ok(Command::Run {
+                days: vec![10],
+                options: RunOptions {
+                    input: InputSource::File(PathBuf::from("inputs/alice/day10.txt")),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse(&["--input=-", "3", "--part", "1"]),
+            Ok(Command::Run {
+                days: vec![3],
+                options: RunOptions {
+                    part: Some(Part::One),
+                    input: InputSource::Stdin,
+                    ..Default::default()
+                },
+            })
+        );
+        assert!(parse(&["--input", "-"]).is_err());
+        assert!(parse(&["1-3", "--input", "-"]).is_err());
+        assert!(parse(&["3", "--input"]).is_err());
+        assert!(parse(&["verify", "3", "--input", "-"]).is_err());
+        assert!(parse(&["3", "--input", "-", "--profile", "bob"]).is_err());
+        assert_eq!(
+            parse(&["--profile", "bob"]),
+            Ok(Command::Run {
+                days: vec![],
+                options: RunOptions {
+                    input: InputSource::Profile("bob".to_string()),
+                    ..Default::default()
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_args() {
+        assert_eq!(
+            parse(&["bench", "3"]),
+            Ok(Command::Bench {
+                days: vec![3],
+                part: None,
+                iterations: DEFAULT_ITERATIONS,
+            })
+        );
+        assert_eq!(
+            parse(&["bench", "-n", "5", "--part", "1"]),
+            Ok(Command::Bench {
+                days: vec![],
+                part: Some(Part::One),
+                iterations: 5,
+            })
+        );
+        assert!(parse(&["bench", "--time"]).is_err());
+        assert!(parse(&["bench", "-n", "0"]).is_err());
+        assert!(parse(&["-n", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_args() {
+        assert_eq!(
+            parse(&["verify", "1-2", "--part", "2"]),
+            Ok(Command::Verify {
+                days: vec![1, 2],
+                part: Some(Part::Two),
+                timeout: None,
+                profile: None,
+            })
+        );
+        assert_eq!(
+            parse(&["verify", "--timeout", "2.5", "--profile", "alice"]),
+            Ok(Command::Verify {
+                days: vec![],
+                part: None,
+                timeout: Some(Duration::from_millis(2500)),
+                profile: Some("alice".to_string()),
+            })
+        );
+        assert!(parse(&["verify", "--timeout", "0"]).is_err());
+        assert!(parse(&["verify", "--time"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_compare_args() {
+        assert_eq!(
+            parse(&["compare", "8", "--part", "2", "--timeout", "10"]),
+            Ok(Command::Compare {
+                days: vec![8],
+                part: Some(Part::Two),
+                timeout: Some(Duration::from_secs(10)),
+            })
+        );
+        assert!(parse(&["compare", "--profile", "alice"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_fetch_args() {
+        assert_eq!(
+            parse(&[
+                "fetch",
+                "1-2",
+                "--base-url",
+                "http://localhost:8000",
+                "--delay",
+                "0.5"
+            ]),
+            Ok(Command::Fetch {
+                days: vec![1, 2],
+                base_url: "http://localhost:8000".to_string(),
+                delay: Duration::from_millis(500),
+            })
+        );
+        assert!(parse(&["fetch", "--part", "1"]).is_err());
+        assert!(parse(&["fetch", "--delay", "-1"]).is_err());
+        assert!(parse(&["3", "--base-url", "http://localhost:8000"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_submit_args() {
+        assert_eq!(
+            parse(&["submit", "13", "2", "--base-url=http://localhost:8000"]),
+            Ok(Command::Submit {
+                day: 13,
+                part: Part::Two,
+                base_url: "http://localhost:8000".to_string(),
+            })
+        );
+        assert!(parse(&["submit", "13"]).is_err());
+        assert!(parse(&["submit", "13", "3"]).is_err());
+        assert!(parse(&["submit", "1-2", "1"]).is_err());
+        assert!(parse(&["submit", "13", "2", "--delay", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_args() {
+        assert_eq!(
+            parse(&["new", "19", "--title", "Aplenty"]),
+            Ok(Command::New {
+                day: 19,
+                title: "Aplenty".to_string(),
+            })
+        );
+        assert_eq!(
+            parse(&["new", "20"]),
+            Ok(Command::New {
+                day: 20,
+                title: String::new(),
+            })
+        );
+        assert!(parse(&["new"]).is_err());
+        assert!(parse(&["new", "19-20"]).is_err());
+        assert!(parse(&["new", "26"]).is_err());
+        assert!(parse(&["19", "--title", "Aplenty"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_examples_args() {
+        assert_eq!(
+            parse(&["examples", "day6.html"]),
+            Ok(Command::Examples {
+                page: PathBuf::from("day6.html"),
+            })
+        );
+        assert!(parse(&["examples"]).is_err());
+        assert!(parse(&["examples", "day6.html", "day7.html"]).is_err());
+        assert!(parse(&["examples", "day6.html", "--part", "1"]).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+// Solutions for Advent of Code 2023, usable from benches, integration tests
+// and other tools. Each day is a `Solution` in `aoc::dayN`, and the binary
+// is a command line front end over this crate.
+pub mod aoc;
+
+pub use aoc::answer::Answer;
+pub use aoc::config::{config_path, Config};
+pub use aoc::input::{
+    base_input_dir, input_dir, input_dir_for, profiles_in, read_input_file, read_input_for_day,
+    read_input_from_dir, InputError, InputSource,
+};
+pub use aoc::rejected::Rejected;
+pub use aoc::report::{DayReport, Format, Record, Status};
+pub use aoc::runner::{run_days, solve_day, RunOptions};
+pub use aoc::solution::{DynSolution, PartStatus, Solution};
+pub use aoc::{find_solution, solutions, solve, Part};
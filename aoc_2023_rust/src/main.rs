@@ -1,5 +1,102 @@
-mod aoc;
+mod cli;
 
-fn main() {
-    aoc::run_all()
+use cli::Command;
+use rust_aoc_2023::aoc;
+use rust_aoc_2023::aoc::config::{config_path, Config};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let config = match Config::load(&config_path()) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    config.apply_to_env();
+
+    let command = match cli::parse_args(std::env::args().skip(1), &config) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{err}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Run { days, options } => aoc::runner::run_days(&days, &options),
+        Command::Bench {
+            days,
+            part,
+            iterations,
+        } => aoc::bench::bench_days(&days, part, iterations),
+        Command::Verify {
+            days,
+            part,
+            timeout,
+            profile,
+        } => {
+            if !aoc::verify::verify_days(&days, part, timeout, profile.as_deref()) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Compare {
+            days,
+            part,
+            timeout,
+        } => {
+            if !aoc::compare::compare_profiles(&days, part, timeout) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Fetch {
+            days,
+            base_url,
+            delay,
+        } => {
+            if !aoc::fetch::fetch_days(&days, &base_url, delay) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            base_url,
+        } => {
+            if !aoc::submit::submit(day, part, &base_url) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::New { day, title } => {
+            match aoc::scaffold::new_day(&aoc::scaffold::source_dir(), day, &title) {
+                Ok(path) => println!("Created {} and registered day {day}", path.display()),
+                Err(err) => {
+                    println!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Examples { page } => {
+            match aoc::examples::import_examples(&aoc::scaffold::source_dir(), &page) {
+                Ok(examples) => {
+                    for example in examples {
+                        println!("Part{}: {}", example.part, example.answer);
+                    }
+                }
+                Err(err) => {
+                    println!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::List => {
+            for solution in aoc::solutions() {
+                println!("Day {:>2}: {}", solution.day(), solution.title());
+            }
+        }
+        Command::Status => print!("{}", aoc::calendar::render(&aoc::solutions())),
+        Command::Help => println!("{}", cli::USAGE),
+    }
+
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,34 @@
+use rust_aoc_2023::aoc::day6::Day6;
+use rust_aoc_2023::{
+    find_solution, solve, solve_day, Answer, Part, Rejected, RunOptions, Solution, Status,
+};
+
+const DAY6: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+#[test]
+fn test_solve_a_day_directly() {
+    let races = Day6::parse(DAY6);
+    assert_eq!(Day6::part1(&races), "288");
+    assert_eq!(Day6::part2(&races), "71503");
+}
+
+#[test]
+fn test_solve_by_day_number() {
+    assert_eq!(solve(6, Part::Two, DAY6), Some(Answer::from("71503")));
+    assert_eq!(find_solution(6).map(|day| day.title()), Some("Wait For It"));
+}
+
+#[test]
+fn test_run_a_day() {
+    let report = solve_day(
+        find_solution(6).unwrap(),
+        DAY6,
+        &RunOptions::default(),
+        &Rejected::default(),
+    );
+    assert!(report
+        .records
+        .iter()
+        .all(|record| record.status == Status::Ok));
+    assert_eq!(report.records[0].answer.as_deref(), Some("288"));
+}